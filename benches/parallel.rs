@@ -0,0 +1,71 @@
+//! Time spent scanning many small files, one after another and with `scan_many`
+//!
+//! Run with `cargo bench --features corpus,parallel --bench parallel`. Each line is the
+//! best of a few runs over 20,000 generated files of about 2 KB, the shape of a monorepo
+//! scan. The rayon pool is rebuilt with 1, 2, 4 and all threads to show how it scales.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use fastsecrets::corpus::{generate_document, CorpusSpec};
+use fastsecrets::{warm_up, Scanner};
+
+const RUNS: usize = 3;
+const FILES: u64 = 20_000;
+
+fn best_of(label: &str, mut run: impl FnMut() -> usize) -> Duration {
+    let mut best = Duration::MAX;
+    let mut found = 0;
+    for _ in 0..RUNS {
+        let started = Instant::now();
+        found = run();
+        best = best.min(started.elapsed());
+    }
+    println!(
+        "{label:<40} {:>10.1} ms  ({found} matches)",
+        best.as_secs_f64() * 1e3
+    );
+    best
+}
+
+fn main() {
+    let files: Vec<(PathBuf, String)> = (0..FILES)
+        .map(|seed| {
+            let (document, _) = generate_document(&CorpusSpec {
+                size: 2048,
+                seed,
+                ..CorpusSpec::default()
+            });
+            (PathBuf::from(format!("src/module{seed}.rs")), document)
+        })
+        .collect();
+    let scanner = Scanner::default();
+    warm_up();
+
+    let sequential = best_of("scan_path, one file after another", || {
+        files
+            .iter()
+            .map(|(path, content)| scanner.scan_path(&path.to_string_lossy(), content).len())
+            .sum()
+    });
+    let cores = std::thread::available_parallelism().map_or(4, |n| n.get());
+    let mut pools = vec![1, 2, 4, cores];
+    pools.sort_unstable();
+    pools.dedup();
+    for threads in pools {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("Thread pool");
+        let parallel = best_of(&format!("scan_many, pool of {threads}"), || {
+            pool.install(|| scanner.scan_many(files.clone()))
+                .iter()
+                .map(|(_, found)| found.len())
+                .sum()
+        });
+        println!(
+            "speedup: {:.1}x",
+            sequential.as_secs_f64() / parallel.as_secs_f64()
+        );
+    }
+}
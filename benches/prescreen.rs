@@ -0,0 +1,88 @@
+//! Time spent on large inputs, with and without the prescreen
+//!
+//! Run with `cargo bench --features corpus --bench prescreen`. Each line is the best of a
+//! few runs over a 10 MB generated document: one without secrets or near-misses, the
+//! common case in CI, and one at the corpus's default densities, where the anchored
+//! detectors have literal hits to scan around.
+
+use std::time::{Duration, Instant};
+
+use fastsecrets::corpus::{generate_document, CorpusSpec};
+use fastsecrets::{default_detectors, detect_all_with_spans, Detector, Prescreen};
+
+const RUNS: usize = 5;
+
+fn best_of(label: &str, mut run: impl FnMut() -> usize) -> Duration {
+    let mut best = Duration::MAX;
+    let mut found = 0;
+    for _ in 0..RUNS {
+        let started = Instant::now();
+        found = run();
+        best = best.min(started.elapsed());
+    }
+    println!(
+        "{label:<40} {:>10.1} ms  ({found} matches)",
+        best.as_secs_f64() * 1e3
+    );
+    best
+}
+
+fn compare(title: &str, spec: CorpusSpec, detectors: &[Box<dyn Detector>], prescreen: &Prescreen) {
+    let (document, _) = generate_document(&spec);
+    println!("{title}");
+
+    let every = best_of("every detector, one after another", || {
+        detectors
+            .iter()
+            .map(|detector| detector.detect(&document).len())
+            .sum()
+    });
+    let screened = best_of("prescreened detectors, whole input", || {
+        detectors
+            .iter()
+            .zip(prescreen.candidates(&document))
+            .filter(|(_, candidate)| *candidate)
+            .map(|(detector, _)| detector.detect(&document).len())
+            .sum()
+    });
+    let windowed = best_of("prescreened detectors, anchor windows", || {
+        detectors
+            .iter()
+            .zip(prescreen.screen(&document))
+            .map(|(detector, screen)| screen.run(detector.as_ref(), &document).len())
+            .sum()
+    });
+    best_of("detect_all_with_spans", || {
+        detect_all_with_spans(&document).len()
+    });
+    println!(
+        "speedup: {:.1}x prescreened, {:.1}x with anchor windows\n",
+        every.as_secs_f64() / screened.as_secs_f64(),
+        every.as_secs_f64() / windowed.as_secs_f64()
+    );
+}
+
+fn main() {
+    let detectors = default_detectors();
+    let prescreen = Prescreen::new(&detectors);
+    compare(
+        "10 MB without secrets",
+        CorpusSpec {
+            size: 10 * 1024 * 1024,
+            secret_density: 0.0,
+            near_miss_density: 0.0,
+            ..CorpusSpec::default()
+        },
+        &detectors,
+        &prescreen,
+    );
+    compare(
+        "10 MB at default densities",
+        CorpusSpec {
+            size: 10 * 1024 * 1024,
+            ..CorpusSpec::default()
+        },
+        &detectors,
+        &prescreen,
+    );
+}
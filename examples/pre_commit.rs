@@ -0,0 +1,33 @@
+//! A `pre-commit` hook that refuses commits adding high-confidence secrets
+//!
+//! Build with `cargo build --release --example pre-commit --features git` and copy the
+//! binary to `.git/hooks/pre-commit`. Only lines the commit adds are scanned, so secrets
+//! already in the history don't block unrelated commits.
+
+use std::path::Path;
+use std::process::ExitCode;
+
+use fastsecrets::git::scan_staged;
+use fastsecrets::report::Severity;
+
+fn main() -> ExitCode {
+    // Git runs the hook at the root of the working tree
+    let findings = match scan_staged(Path::new(".")) {
+        Ok(findings) => findings,
+        Err(err) => {
+            eprintln!("fastsecrets: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for finding in &findings {
+        eprintln!("fastsecrets: {}", finding.message());
+    }
+    if findings
+        .iter()
+        .any(|finding| finding.severity == Severity::Error)
+    {
+        eprintln!("fastsecrets: commit rejected, unstage the secrets above or use --no-verify");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
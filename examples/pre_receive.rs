@@ -0,0 +1,47 @@
+//! A `pre-receive` hook that refuses pushes adding high-confidence secrets
+//!
+//! Build with `cargo build --release --example pre-receive --features git-hook` and copy
+//! the binary to `hooks/pre-receive` in the server repository. Secrets that were already
+//! accepted can be listed in a baseline file named by `FASTSECRETS_BASELINE`.
+
+use std::io::Read;
+use std::path::Path;
+use std::process::ExitCode;
+
+use fastsecrets::git_hook::pre_receive;
+use fastsecrets::{Baseline, Scanner};
+
+fn main() -> ExitCode {
+    let mut input = String::new();
+    if let Err(err) = std::io::stdin().read_to_string(&mut input) {
+        eprintln!("fastsecrets: could not read ref updates: {err}");
+        return ExitCode::FAILURE;
+    }
+    let baseline = match std::env::var_os("FASTSECRETS_BASELINE") {
+        Some(path) => match Baseline::load(&path) {
+            Ok(baseline) => baseline,
+            Err(err) => {
+                eprintln!("fastsecrets: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Baseline::default(),
+    };
+
+    // Git runs the hook inside the receiving repository
+    let outcome = match pre_receive(Path::new("."), &input, &Scanner::default(), &baseline) {
+        Ok(outcome) => outcome,
+        Err(err) => {
+            eprintln!("fastsecrets: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    for finding in &outcome.findings {
+        eprintln!("fastsecrets: {}", finding.message());
+    }
+    if outcome.rejected() {
+        eprintln!("fastsecrets: push rejected, remove the secrets above from every pushed commit");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
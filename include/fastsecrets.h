@@ -0,0 +1,60 @@
+#ifndef FASTSECRETS_H
+#define FASTSECRETS_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+// Outcome of a call into the library
+typedef enum FsStatus {
+  FS_STATUS_OK = 0,
+  // A required pointer argument was null
+  FS_STATUS_NULL_POINTER = 1,
+  // The content was not valid UTF-8
+  FS_STATUS_INVALID_UTF8 = 2,
+  // The scan panicked; nothing was returned
+  FS_STATUS_PANIC = 3,
+} FsStatus;
+
+// A secret found by [`fastsecrets_scan`]
+//
+// The strings are owned by the array they came in and stay valid until it is passed to
+// [`fastsecrets_free_findings`].
+typedef struct FsFinding {
+  // Reported type name, e.g. `GitLab Token`, as UTF-8 without a NUL terminator
+  const uint8_t *type_name;
+  size_t type_name_len;
+  // The secret value, as UTF-8 without a NUL terminator
+  const uint8_t *value;
+  size_t value_len;
+  // Byte offset in the content where the value starts
+  size_t start;
+  // Byte offset in the content where the value ends
+  size_t end;
+} FsFinding;
+
+// Scans UTF-8 content with every built-in detector
+//
+// On success, `*out` points to `*out_len` findings ordered by where they start, to be
+// freed with [`fastsecrets_free_findings`]. When nothing is found, `*out` is null and
+// `*out_len` is 0. On any other status, `*out` and `*out_len` are left as they were.
+//
+// # Safety
+// `content` must point to `len` readable bytes; it may be null only when `len` is 0.
+// `out` and `out_len` must be valid for writes.
+enum FsStatus fastsecrets_scan(const uint8_t *content,
+                               size_t len,
+                               struct FsFinding **out,
+                               size_t *out_len);
+
+// Frees findings returned by [`fastsecrets_scan`], with their strings
+//
+// Passing a null `findings` does nothing.
+//
+// # Safety
+// `findings` and `len` must be what one successful call to [`fastsecrets_scan`] wrote
+// to `*out` and `*out_len`, and must not be used or freed again afterwards.
+void fastsecrets_free_findings(struct FsFinding *findings, size_t len);
+
+#endif  /* FASTSECRETS_H */
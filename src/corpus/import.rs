@@ -0,0 +1,176 @@
+//! Importing sample strings from other scanners' test corpora
+//!
+//! Both formats are read as JSON:
+//!
+//! - gitleaks reports, as stored in its `testdata`: an array of findings, each with a
+//!   `RuleID` and the `Match` (or, failing that, the `Secret`) the rule must report.
+//! - detect-secrets test vectors: an array of `{"plugin", "line", "should_flag"}`
+//!   objects, one per `(line, should_flag)` pair of a plugin's parametrized tests.
+//!
+//! Rule and plugin names are mapped onto [`SecretKind`] with [`kind_for_rule`]. Names
+//! with no mapping are kept, with `kind: None`, so an evaluation can report them.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use super::SecretKind;
+
+/// Rule names from other scanners, and the kind whose detector covers them
+const RULE_NAMES: &[(&str, SecretKind)] = &[
+    // gitleaks rule IDs
+    ("gitlab-pat", SecretKind::GitLab),
+    ("gitlab-ptt", SecretKind::GitLab),
+    ("gitlab-rrt", SecretKind::GitLab),
+    ("stripe-access-token", SecretKind::Stripe),
+    ("npm-access-token", SecretKind::Npm),
+    ("digitalocean-pat", SecretKind::DigitalOcean),
+    ("digitalocean-access-token", SecretKind::DigitalOcean),
+    ("slack-bot-token", SecretKind::Slack),
+    ("slack-user-token", SecretKind::Slack),
+    ("slack-legacy-token", SecretKind::Slack),
+    ("twilio-api-key", SecretKind::Twilio),
+    // detect-secrets plugin names
+    ("GitLabTokenDetector", SecretKind::GitLab),
+    ("StripeDetector", SecretKind::Stripe),
+    ("NpmDetector", SecretKind::Npm),
+    ("SlackDetector", SecretKind::Slack),
+    ("TwilioKeyDetector", SecretKind::Twilio),
+];
+
+/// Error returned when a corpus file can't be imported
+#[derive(Debug)]
+pub enum ImportError {
+    /// The file is not valid JSON, or not an array
+    Json(String),
+    /// An entry of the array is missing a field or has the wrong type
+    Entry { index: usize, reason: String },
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::Json(reason) => write!(f, "invalid corpus file: {reason}"),
+            ImportError::Entry { index, reason } => {
+                write!(f, "invalid corpus entry [{index}]: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+/// One sample string and what a scan of it should report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorpusCase {
+    /// The rule or plugin name as written in the corpus
+    pub rule: String,
+    /// The kind `rule` maps to, or `None` when there is no mapping
+    pub kind: Option<SecretKind>,
+    /// The text to scan
+    pub input: String,
+    /// Whether the rule must report `input`
+    pub should_flag: bool,
+}
+
+impl CorpusCase {
+    fn new(rule: &str, input: &str, should_flag: bool) -> Self {
+        CorpusCase {
+            rule: rule.to_string(),
+            kind: kind_for_rule(rule),
+            input: input.to_string(),
+            should_flag,
+        }
+    }
+}
+
+/// Maps a gitleaks rule ID or a detect-secrets plugin name to a [`SecretKind`]
+///
+/// # Arguments
+/// * `rule` - The name as written in the corpus, e.g. "slack-bot-token"
+///
+/// # Returns
+/// * `Option<SecretKind>` - The kind, or `None` for rules with no counterpart here
+pub fn kind_for_rule(rule: &str) -> Option<SecretKind> {
+    RULE_NAMES
+        .iter()
+        .find(|(name, _)| *name == rule)
+        .map(|&(_, kind)| kind)
+}
+
+/// Parses `source` as a JSON array and calls `case` on each object in it
+fn entries(
+    source: &str,
+    mut case: impl FnMut(&Value) -> Result<CorpusCase, String>,
+) -> Result<Vec<CorpusCase>, ImportError> {
+    let entries: Value =
+        serde_json::from_str(source).map_err(|err| ImportError::Json(err.to_string()))?;
+    let entries = entries
+        .as_array()
+        .ok_or_else(|| ImportError::Json("expected an array of entries".to_string()))?;
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| case(entry).map_err(|reason| ImportError::Entry { index, reason }))
+        .collect()
+}
+
+/// Reads a string field of a corpus entry
+fn string_field<'a>(entry: &'a Value, field: &str) -> Result<&'a str, String> {
+    entry[field]
+        .as_str()
+        .ok_or_else(|| format!("`{field}` must be a string"))
+}
+
+/// Imports a gitleaks report as positive cases
+///
+/// # Arguments
+/// * `source` - A gitleaks JSON report
+///
+/// # Returns
+/// * `Result<Vec<CorpusCase>, ImportError>` - One case per finding, in file order
+pub fn gitleaks(source: &str) -> Result<Vec<CorpusCase>, ImportError> {
+    entries(source, |entry| {
+        let rule = string_field(entry, "RuleID")?;
+        let input = string_field(entry, "Match").or_else(|_| string_field(entry, "Secret"))?;
+        Ok(CorpusCase::new(rule, input, true))
+    })
+}
+
+/// Imports detect-secrets test vectors
+///
+/// # Arguments
+/// * `source` - A JSON array of `{"plugin", "line", "should_flag"}` objects
+///
+/// # Returns
+/// * `Result<Vec<CorpusCase>, ImportError>` - One case per vector, in file order
+pub fn detect_secrets(source: &str) -> Result<Vec<CorpusCase>, ImportError> {
+    entries(source, |entry| {
+        let should_flag = entry["should_flag"]
+            .as_bool()
+            .ok_or_else(|| "`should_flag` must be a boolean".to_string())?;
+        Ok(CorpusCase::new(
+            string_field(entry, "plugin")?,
+            string_field(entry, "line")?,
+            should_flag,
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_entries_are_errors() {
+        assert!(matches!(gitleaks("{}"), Err(ImportError::Json(_))));
+        assert!(matches!(
+            gitleaks(r#"[{"RuleID": "gitlab-pat", "Match": "glpat-x"}, {"RuleID": 1}]"#),
+            Err(ImportError::Entry { index: 1, .. })
+        ));
+        assert!(matches!(
+            detect_secrets(r#"[{"plugin": "SlackDetector", "line": "x", "should_flag": "yes"}]"#),
+            Err(ImportError::Entry { index: 0, reason }) if reason.contains("should_flag")
+        ));
+    }
+}
@@ -0,0 +1,110 @@
+//! Telling words and identifiers apart from random secrets
+//!
+//! Generic detectors report any value assigned to a secret-looking name, so they also
+//! report values like `getUserAccessTokenFromCache` that are code, not credentials.
+//! [`word_fraction`] splits a value into the words of its camelCase, snake_case and
+//! kebab-case parts and measures how much of it is made of words from an embedded list
+//! of common English words and identifier fragments. See
+//! [`crate::ScannerBuilder::demote_dictionary_words`].
+
+use std::collections::HashSet;
+
+use once_cell::sync::Lazy;
+
+/// About a thousand common English words and identifier fragments, one per line
+static WORDS: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| include_str!("dictionary/words.txt").lines().collect());
+
+/// Shortest part counted as a word; shorter ones are as likely to be random
+const MIN_WORD_LEN: usize = 2;
+
+/// Splits an identifier-like value into its parts
+///
+/// Parts are separated by anything that isn't a letter or digit, by a change between
+/// letters and digits, and by a lowercase-to-uppercase change. A run of capitals followed
+/// by a lowercase letter ends before its last capital, so `HTTPServer` is `HTTP`,
+/// `Server`.
+///
+/// # Arguments
+/// * `value` - The value to split, e.g. `getUserAccessToken` or `db_password_2`
+///
+/// # Returns
+/// * `Vec<&str>` - The parts in order, with their case kept
+pub fn split_identifier(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    for run in value.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<(usize, char)> = run.char_indices().collect();
+        let mut start = 0;
+        for (i, &(at, c)) in chars.iter().enumerate().skip(1) {
+            let previous = chars[i - 1].1;
+            let next = chars.get(i + 1).map(|&(_, next)| next);
+            let boundary = previous.is_alphabetic() != c.is_alphabetic()
+                || (previous.is_lowercase() && c.is_uppercase())
+                || (previous.is_uppercase()
+                    && c.is_uppercase()
+                    && next.is_some_and(char::is_lowercase));
+            if boundary {
+                parts.push(&run[start..at]);
+                start = at;
+            }
+        }
+        if start < run.len() {
+            parts.push(&run[start..]);
+        }
+    }
+    parts
+}
+
+/// Checks whether a part is a known word, ignoring case
+fn is_word(part: &str) -> bool {
+    part.chars().count() >= MIN_WORD_LEN && WORDS.contains(part.to_lowercase().as_str())
+}
+
+/// Measures how much of a value is made of dictionary words
+///
+/// # Arguments
+/// * `value` - A candidate secret
+///
+/// # Returns
+/// * `f64` - Characters in word parts over all letters and digits, from 0.0 for a
+///   random string to 1.0 for an identifier made only of words
+pub fn word_fraction(value: &str) -> f64 {
+    let parts = split_identifier(value);
+    let total: usize = parts.iter().map(|part| part.chars().count()).sum();
+    if total == 0 {
+        return 0.0;
+    }
+    let in_words: usize = parts
+        .iter()
+        .filter(|part| is_word(part))
+        .map(|part| part.chars().count())
+        .sum();
+    in_words as f64 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_identifier() {
+        assert_eq!(
+            split_identifier("getUserAccessTokenFromCache"),
+            vec!["get", "User", "Access", "Token", "From", "Cache"]
+        );
+        assert_eq!(
+            split_identifier("HTTPServer_db-password2"),
+            vec!["HTTP", "Server", "db", "password", "2"]
+        );
+        assert_eq!(split_identifier("__"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_word_fraction() {
+        assert_eq!(word_fraction("getUserAccessTokenFromCache"), 1.0);
+        assert_eq!(word_fraction("hK9xQ2vLm8Pz3RtY7wNcB4"), 0.0);
+        assert_eq!(word_fraction(""), 0.0);
+        // "reader" is a word, "3f9a" isn't
+        assert_eq!(word_fraction("reader3f9a"), 0.6);
+    }
+}
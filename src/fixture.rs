@@ -0,0 +1,135 @@
+//! Telling real credentials in test fixtures from the made-up ones around them
+//!
+//! Test directories are full of fake keys, so a scanner built with
+//! [`crate::ScannerBuilder::demote_test_fixtures`] lowers matches in test paths to
+//! [`crate::Confidence::Low`]. Now and then a fixture holds a real key, pasted in to
+//! record a response or reproduce a bug. Such a match passes its format's checksum or
+//! sits next to a production hostname, and is raised instead, unless a local or
+//! documentation host like `localhost` or `example.com` is next to it too.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::placeholder::{is_placeholder_host, PLACEHOLDER_HOSTS};
+use crate::secrets::context::context_window;
+use crate::secrets::evidence::{Direction, Evidence, Signal, DECISIVE};
+use crate::SecretMatch;
+
+/// Directory names that hold tests and their fixtures, wherever they are in the path
+const TEST_DIRS: &[&str] = &[
+    "test",
+    "tests",
+    "__tests__",
+    "spec",
+    "specs",
+    "testdata",
+    "fixtures",
+    "__fixtures__",
+    "__mocks__",
+];
+
+/// Endings of test file names outside a test directory, e.g. `client_test.go`
+const TEST_FILE_SUFFIXES: &[&str] = &["_test.go", "_test.py", "_test.rs", "_spec.rb"];
+
+/// Markers in the names of JavaScript and TypeScript tests, e.g. `client.spec.ts`
+const TEST_FILE_MARKERS: &[&str] = &[".test.", ".spec."];
+
+/// Bytes on either side of a match searched for hostnames
+const HOST_CONTEXT_RADIUS: usize = 200;
+
+/// Regex pattern for the host of a URL
+/// Pattern captures: (1) the host, without credentials or port
+static URL_HOST_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)\b[a-z][a-z0-9+.-]*://(?:[^\s/@'"<>]*@)?([a-z0-9.-]+)"#)
+        .expect("Invalid regex pattern")
+});
+
+/// Checks whether a path, with `/` separators, is a test or fixture file
+pub(crate) fn is_test_path(path: &str) -> bool {
+    let mut parts = path.split('/').rev();
+    let name = parts.next().unwrap_or_default();
+    parts.any(|dir| TEST_DIRS.contains(&dir))
+        || name.starts_with("test_") && name.ends_with(".py")
+        || TEST_FILE_SUFFIXES
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+        || TEST_FILE_MARKERS.iter().any(|marker| name.contains(marker))
+}
+
+/// Checks whether a match in a fixture looks like a real credential
+///
+/// # Arguments
+/// * `content` - The scanned file
+/// * `found` - A match found in `content`
+///
+/// # Returns
+/// * `bool` - true if the match passed a checksum or is next to a production host, and
+///   no local or documentation host is next to it
+fn looks_real(content: &str, found: &SecretMatch) -> bool {
+    let context = context_window(content, found.start, found.end, HOST_CONTEXT_RADIUS);
+    let hosts: Vec<String> = URL_HOST_PATTERN
+        .captures_iter(context)
+        .map(|captures| captures[1].trim_end_matches('.').to_ascii_lowercase())
+        .collect();
+    if hosts
+        .iter()
+        .any(|host| is_placeholder_host(host, PLACEHOLDER_HOSTS))
+    {
+        return false;
+    }
+    let checksum_valid = found.evidence.iter().any(|evidence| {
+        evidence.signal == Signal::Checksum && evidence.direction == Direction::Raise
+    });
+    checksum_valid
+        || hosts
+            .iter()
+            .any(|host| host.contains('.') && !host.chars().all(|c| c.is_ascii_digit() || c == '.'))
+}
+
+/// Lowers the matches in a test fixture, and flags and raises those that look real
+///
+/// # Arguments
+/// * `path` - The scanned file's path, with `/` separators
+/// * `content` - The file's contents
+/// * `secrets` - Matches found in `content`
+pub(crate) fn mark_fixtures(path: &str, content: &str, secrets: &mut [SecretMatch]) {
+    if !is_test_path(path) {
+        return;
+    }
+    for found in secrets.iter_mut() {
+        if looks_real(content, found) {
+            found.suspected_real_in_fixture = true;
+            found.add_evidence(Evidence::raise(Signal::TestFixture, 1));
+        } else {
+            found.add_evidence(Evidence::lower(Signal::TestFixture, DECISIVE));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_test_paths() {
+        for path in [
+            "tests/api.rs",
+            "src/__tests__/client.js",
+            "pkg/client/testdata/response.json",
+            "spec/fixtures/keys.yml",
+            "client_test.go",
+            "tests.py/test_client.py",
+            "web/client.spec.ts",
+        ] {
+            assert!(is_test_path(path), "{path}");
+        }
+        for path in [
+            "src/client.rs",
+            "tests",
+            "latest/config.yml",
+            "contest/main.go",
+        ] {
+            assert!(!is_test_path(path), "{path}");
+        }
+    }
+}
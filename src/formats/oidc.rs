@@ -0,0 +1,284 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::secrets::detector::Detector;
+use crate::secrets::secret_match::SecretMatch;
+use crate::secrets::secret_type::SecretType;
+
+/// Regex pattern for the members that mark a document as an OIDC client registration
+static OIDC_REGISTRATION_MARKER_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#""(?:redirect_uris|grant_types)"\s*:"#).expect("Invalid regex pattern")
+});
+
+/// Regex pattern for client credentials in an OIDC client registration
+/// Pattern captures: (1) the member name, (2) the quoted value
+static OIDC_CREDENTIAL_MEMBER_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#""(client_secret|registration_access_token)"\s*:\s*("(?:[^"\\]|\\.)*")"#)
+        .expect("Invalid regex pattern")
+});
+
+/// A JSON object found while scanning, with the string values of its members
+#[derive(Default)]
+struct JsonObject {
+    /// Member names with the byte span of their value if it is a string
+    members: Vec<(String, Option<(usize, usize)>)>,
+}
+
+impl JsonObject {
+    /// Returns whether the object has a member with this name
+    fn has(&self, name: &str) -> bool {
+        self.members.iter().any(|(member, _)| member == name)
+    }
+
+    /// Returns the span of a member's string value
+    fn string_span(&self, name: &str) -> Option<(usize, usize)> {
+        self.members
+            .iter()
+            .find(|(member, _)| member == name)
+            .and_then(|(_, span)| *span)
+    }
+}
+
+/// Returns the byte offset just past the closing quote of a string starting at `start`
+///
+/// Returns None for a string that is not closed before the end of the input.
+fn string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Collects every JSON object in `content`, innermost objects first
+///
+/// This is a lenient scanner rather than a parser, so JWKs embedded in source code,
+/// YAML or partial documents are still found.
+fn json_objects(content: &str) -> Vec<JsonObject> {
+    let bytes = content.as_bytes();
+    let mut objects = Vec::new();
+    let mut stack: Vec<JsonObject> = Vec::new();
+    let mut pending_key: Option<String> = None;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                let Some(end) = string_end(bytes, i) else {
+                    break;
+                };
+                let rest = content[end..].trim_start();
+                if rest.starts_with(':') {
+                    pending_key = Some(content[i + 1..end - 1].to_string());
+                } else if let (Some(key), Some(object)) = (pending_key.take(), stack.last_mut()) {
+                    object.members.push((key, Some((i + 1, end - 1))));
+                }
+                i = end;
+                continue;
+            }
+            b'{' => {
+                if let (Some(key), Some(object)) = (pending_key.take(), stack.last_mut()) {
+                    object.members.push((key, None));
+                }
+                stack.push(JsonObject::default());
+            }
+            b'}' => {
+                pending_key = None;
+                if let Some(object) = stack.pop() {
+                    objects.push(object);
+                }
+            }
+            b':' | b',' => {}
+            c if c.is_ascii_whitespace() => {}
+            _ => {
+                if let (Some(key), Some(object)) = (pending_key.take(), stack.last_mut()) {
+                    object.members.push((key, None));
+                }
+            }
+        }
+        i += 1;
+    }
+
+    objects
+}
+
+/// Detects private key material in JSON Web Keys
+///
+/// A JWK with a `d` member holds a private key (EC, OKP or RSA private exponent);
+/// public JWKs only carry `x`/`y` or `n`/`e` and are never reported.
+fn detect_private_jwks(content: &str) -> Vec<SecretMatch> {
+    json_objects(content)
+        .into_iter()
+        .filter(|object| object.has("kty"))
+        .filter_map(|object| object.string_span("d"))
+        .filter(|(start, end)| end > start)
+        .map(|(start, end)| {
+            SecretMatch::new(SecretType::PrivateJwk, &content[start..end], start, end)
+        })
+        .collect()
+}
+
+/// Detects client credentials in OIDC dynamic client registration documents
+///
+/// Only documents with `redirect_uris` or `grant_types` members are considered, so a
+/// `client_secret` in unrelated JSON is left to other detectors.
+fn detect_oidc_client_registration(content: &str) -> Vec<SecretMatch> {
+    if !OIDC_REGISTRATION_MARKER_PATTERN.is_match(content) {
+        return Vec::new();
+    }
+
+    let mut secrets = Vec::new();
+    for captures in OIDC_CREDENTIAL_MEMBER_PATTERN.captures_iter(content) {
+        let (Some(name), Some(quoted)) = (captures.get(1), captures.get(2)) else {
+            continue;
+        };
+        let Ok(value) = serde_json::from_str::<String>(quoted.as_str()) else {
+            continue;
+        };
+        if value.is_empty() || value.starts_with('$') || value.starts_with("{{") {
+            continue;
+        }
+        let secret_type = match name.as_str() {
+            "client_secret" => SecretType::OidcClientSecret,
+            _ => SecretType::OidcRegistrationAccessToken,
+        };
+        secrets.push(SecretMatch::new(
+            secret_type,
+            value,
+            quoted.start() + 1,
+            quoted.end() - 1,
+        ));
+    }
+
+    secrets
+}
+
+/// Detects secrets in OpenID Connect client registrations and JSON Web Keys
+///
+/// Covers:
+/// - `client_secret` and `registration_access_token` in client registration requests,
+///   responses and IdP client exports
+/// - Private JWKs (any JWK object with a `d` member), including keys inside a JWKS
+///   or a client's inline `jwks`
+///
+/// # Arguments
+/// * `content` - The string to check for OIDC secrets
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_oidc_secrets(content: &str) -> Vec<SecretMatch> {
+    let mut secrets = detect_oidc_client_registration(content);
+    secrets.extend(detect_private_jwks(content));
+    secrets
+}
+
+/// Detector registered as `"oidc"`; see [`detect_oidc_secrets`]
+pub struct OidcDetector;
+
+impl Detector for OidcDetector {
+    fn name(&self) -> &str {
+        "oidc"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_oidc_secrets(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        // Member names are matched as written, without resolving JSON escapes
+        Some(r#""(?:kty|redirect_uris|grant_types)""#.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRATION_RESPONSE: &str = r#"{
+  "client_id": "s6BhdRkqt3",
+  "client_secret": "ZJYCqe3GGRvdrudKyZS0XhGv_Z45DuKhCUk0gBR1vZk",
+  "client_secret_expires_at": 1577858400,
+  "registration_access_token": "this.is.an.access.token.value.ffx83",
+  "registration_client_uri": "https://server.example.com/connect/register?client_id=s6BhdRkqt3",
+  "token_endpoint_auth_method": "client_secret_basic",
+  "application_type": "web",
+  "redirect_uris": ["https://client.example.org/callback"],
+  "grant_types": ["authorization_code", "refresh_token"]
+}"#;
+
+    const PRIVATE_EC_JWK: &str = r#"{
+  "kty": "EC",
+  "crv": "P-256",
+  "x": "f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU",
+  "y": "x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0",
+  "d": "jpsQnnGQmL-YBIffH1136cspYG6-0iY7X1fCE9-E9LI",
+  "use": "sig",
+  "kid": "1"
+}"#;
+
+    const PUBLIC_JWKS: &str = r#"{"keys": [
+  {"kty": "EC", "crv": "P-256", "x": "MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4", "y": "4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM", "use": "enc", "kid": "1"},
+  {"kty": "RSA", "n": "0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W-5JsGY4Hc5n9yBXArwl93lqt7_RN5w6Cf0h4QyQ5v-65YGjQR0_FDW2QvzqY368QQMicAtaSqzs8KJZgnYb9c7d0zgdAZHzu6qMQvRL5hajrn1n91CbOpbISD08qNLyrdkt-bFTWhAI4vMQFh6WeZu0fM4lFd2NcRwr3XPksINHaQ-G_xBniIqbw0Ls1jF44-csFCur-kEgU8awapJzKnqDKgw", "e": "AQAB", "alg": "RS256", "kid": "2011-04-29"}
+]}"#;
+
+    #[test]
+    fn test_dynamic_registration_response() {
+        let result = detect_oidc_secrets(REGISTRATION_RESPONSE);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].secret_type, "OIDC Client Secret");
+        assert_eq!(
+            result[0].value,
+            "ZJYCqe3GGRvdrudKyZS0XhGv_Z45DuKhCUk0gBR1vZk"
+        );
+        assert_eq!(result[1].secret_type, "OIDC Registration Access Token");
+        assert_eq!(
+            &REGISTRATION_RESPONSE[result[1].start..result[1].end],
+            "this.is.an.access.token.value.ffx83"
+        );
+    }
+
+    #[test]
+    fn test_client_secret_without_registration_members_is_ignored() {
+        let content = r#"{"client_id": "abc", "client_secret": "not-an-oidc-registration"}"#;
+        assert!(detect_oidc_secrets(content).is_empty());
+    }
+
+    #[test]
+    fn test_private_ec_jwk() {
+        let result = detect_oidc_secrets(PRIVATE_EC_JWK);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Private JWK");
+        assert_eq!(
+            result[0].value,
+            "jpsQnnGQmL-YBIffH1136cspYG6-0iY7X1fCE9-E9LI"
+        );
+        assert_eq!(
+            &PRIVATE_EC_JWK[result[0].start..result[0].end],
+            result[0].value
+        );
+    }
+
+    #[test]
+    fn test_private_jwk_inside_client_jwks() {
+        let content = format!(
+            r#"{{"client_name": "svc", "redirect_uris": [], "jwks": {{"keys": [{PRIVATE_EC_JWK}]}}}}"#
+        );
+        let result = detect_oidc_secrets(&content);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Private JWK");
+    }
+
+    #[test]
+    fn test_unterminated_string_does_not_panic() {
+        assert!(detect_oidc_secrets(r#"{"kty": "EC", "d": "é"#).is_empty());
+    }
+
+    #[test]
+    fn test_public_jwks_is_clean() {
+        assert!(detect_oidc_secrets(PUBLIC_JWKS).is_empty());
+    }
+}
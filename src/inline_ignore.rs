@@ -0,0 +1,105 @@
+//! Inline `fastsecrets:ignore` comments
+//!
+//! A marker suppresses findings on its own line and on the line below it. A marker
+//! with a qualifier, like `fastsecrets:ignore[stripe]` or `fastsecrets:ignore[stripe,
+//! slack]`, only suppresses findings of the named detectors.
+
+/// Text that marks a line's findings, and the next line's, as suppressed
+pub(crate) const IGNORE_MARKER: &str = "fastsecrets:ignore";
+
+/// One marker found in a line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum IgnoreMarker {
+    /// An unqualified marker, suppressing every finding
+    All,
+    /// A qualified marker, with the detector names lowercased
+    Detectors(Vec<String>),
+}
+
+/// Parses every marker in a line
+///
+/// A `[` with no closing `]` on the line is not a qualifier, so the marker before it
+/// suppresses everything.
+fn markers_in_line(line: &str) -> Vec<IgnoreMarker> {
+    line.match_indices(IGNORE_MARKER)
+        .map(|(start, _)| {
+            let rest = &line[start + IGNORE_MARKER.len()..];
+            let qualifier = rest
+                .strip_prefix('[')
+                .and_then(|rest| rest.split_once(']'))
+                .map(|(names, _)| names);
+            match qualifier {
+                Some(names) => IgnoreMarker::Detectors(
+                    names
+                        .split(',')
+                        .map(|name| name.trim().to_ascii_lowercase())
+                        .filter(|name| !name.is_empty())
+                        .collect(),
+                ),
+                None => IgnoreMarker::All,
+            }
+        })
+        .collect()
+}
+
+/// Returns the markers that cover a byte offset: those on its line and the line above
+///
+/// # Arguments
+/// * `content` - The scanned string
+/// * `offset` - Byte offset of a match's start in `content`
+///
+/// # Returns
+/// * `Vec<IgnoreMarker>` - The markers, above-line ones first
+pub(crate) fn markers_covering(content: &str, offset: usize) -> Vec<IgnoreMarker> {
+    let line_start = content[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = content[offset..]
+        .find('\n')
+        .map_or(content.len(), |i| offset + i);
+
+    let mut markers = Vec::new();
+    if line_start > 0 {
+        let above = &content[..line_start - 1];
+        let above_start = above.rfind('\n').map_or(0, |i| i + 1);
+        markers.extend(markers_in_line(&above[above_start..]));
+    }
+    markers.extend(markers_in_line(&content[line_start..line_end]));
+    markers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marker_parsing() {
+        assert_eq!(markers_in_line("key = 1"), Vec::new());
+        assert_eq!(
+            markers_in_line("key = 1  # fastsecrets:ignore"),
+            vec![IgnoreMarker::All]
+        );
+        assert_eq!(
+            markers_in_line("// fastsecrets:ignore[Stripe, slack] fixture"),
+            vec![IgnoreMarker::Detectors(vec![
+                "stripe".to_string(),
+                "slack".to_string()
+            ])]
+        );
+        assert_eq!(
+            markers_in_line("# fastsecrets:ignore[stripe"),
+            vec![IgnoreMarker::All]
+        );
+    }
+
+    #[test]
+    fn test_markers_cover_own_line_and_line_below() {
+        let content = "A\n# fastsecrets:ignore\nB = 1\nC = 2\r\nD # fastsecrets:ignore[npm]";
+        let at = |text: &str| content.find(text).unwrap();
+        assert!(markers_covering(content, at("A")).is_empty());
+        assert_eq!(markers_covering(content, at("B")), vec![IgnoreMarker::All]);
+        assert!(markers_covering(content, at("C")).is_empty());
+        assert_eq!(
+            markers_covering(content, at("D")),
+            vec![IgnoreMarker::Detectors(vec!["npm".to_string()])]
+        );
+    }
+}
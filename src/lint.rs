@@ -0,0 +1,309 @@
+//! Linting user-supplied rules before they are used
+//!
+//! Custom rules and gitleaks imports pile up, and a rule that shadows a built-in
+//! detector, matches the empty string or starts with `.*` is easy to register by
+//! accident. [`crate::ScannerBuilder::validate`] checks each rule that reports its
+//! pattern with [`Detector::pattern`] and returns what it finds as [`RuleLintWarning`]s.
+
+use std::fmt;
+use std::sync::Arc;
+
+use regex_syntax::hir::literal::{ExtractKind, Extractor};
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+use crate::secrets::detector::Detector;
+
+/// Classes with more code points than this are too broad to repeat without a bound,
+/// like `.`, `\w` or `[^"]`; ASCII letters and digits are well under it
+const LARGE_CLASS: u32 = 256;
+
+/// Literal prefixes shorter than this are too common to call a collision, e.g. `a`
+const MIN_PREFIX_LEN: usize = 3;
+
+/// A problem with one rule's pattern or samples
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleLint {
+    /// The rule's matches start with the same literal as other rules' matches, so they
+    /// likely report the same secrets twice
+    PrefixCollision {
+        /// The rule's own literal prefix, e.g. `sk_live_`
+        prefix: String,
+        /// Names of the colliding detectors and rules, e.g. `stripe`
+        rules: Vec<String>,
+    },
+    /// A class of more than 256 characters repeated without an upper bound, such as
+    /// `.*`, which makes the rule slow and its matches run on
+    UnboundedRepetition,
+    /// The pattern can match the empty string, so it reports matches everywhere
+    EmptyMatch,
+    /// A sample given with the rule, see [`Detector::sample`], has no match
+    SampleMissed,
+}
+
+impl RuleLint {
+    /// Whether the rule should be rejected rather than merely reviewed
+    ///
+    /// Only [`RuleLint::EmptyMatch`] is: such a rule is never right.
+    pub fn is_error(&self) -> bool {
+        matches!(self, RuleLint::EmptyMatch)
+    }
+}
+
+/// A lint found in one rule by [`crate::ScannerBuilder::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleLintWarning {
+    /// Name of the rule, as given to its detector
+    pub rule: String,
+    pub lint: RuleLint,
+}
+
+impl fmt::Display for RuleLintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rule = &self.rule;
+        match &self.lint {
+            RuleLint::PrefixCollision { prefix, rules } => write!(
+                f,
+                "rule `{rule}` shares the prefix `{prefix}` with {}",
+                rules
+                    .iter()
+                    .map(|rule| format!("`{rule}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            RuleLint::UnboundedRepetition => write!(
+                f,
+                "rule `{rule}` repeats a broad character class without an upper bound"
+            ),
+            RuleLint::EmptyMatch => write!(f, "rule `{rule}` can match the empty string"),
+            RuleLint::SampleMissed => write!(f, "rule `{rule}` doesn't match its sample"),
+        }
+    }
+}
+
+/// Counts the code points or bytes in a class
+fn class_len(class: &Class) -> u32 {
+    match class {
+        Class::Unicode(class) => class
+            .ranges()
+            .iter()
+            .map(|range| range.end() as u32 - range.start() as u32 + 1)
+            .sum(),
+        Class::Bytes(class) => class
+            .ranges()
+            .iter()
+            .map(|range| u32::from(range.end()) - u32::from(range.start()) + 1)
+            .sum(),
+    }
+}
+
+/// Checks whether a pattern repeats a large class without an upper bound
+fn has_unbounded_repetition(hir: &Hir) -> bool {
+    match hir.kind() {
+        HirKind::Repetition(repetition) => {
+            let broad = match repetition.sub.kind() {
+                HirKind::Class(class) => class_len(class) > LARGE_CLASS,
+                _ => false,
+            };
+            (repetition.max.is_none() && broad) || has_unbounded_repetition(&repetition.sub)
+        }
+        HirKind::Capture(capture) => has_unbounded_repetition(&capture.sub),
+        HirKind::Concat(parts) | HirKind::Alternation(parts) => {
+            parts.iter().any(has_unbounded_repetition)
+        }
+        HirKind::Empty | HirKind::Literal(_) | HirKind::Class(_) | HirKind::Look(_) => false,
+    }
+}
+
+/// Returns the literals every match of a pattern starts with, when there are few enough
+fn literal_prefixes(hir: &Hir) -> Vec<String> {
+    let prefixes = Extractor::new().kind(ExtractKind::Prefix).extract(hir);
+    match prefixes.literals() {
+        Some(literals) => literals
+            .iter()
+            .map(|literal| String::from_utf8_lossy(literal.as_bytes()).into_owned())
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Checks whether two literal prefixes can start the same match
+fn collides(left: &str, right: &str) -> bool {
+    left.len().min(right.len()) >= MIN_PREFIX_LEN
+        && (left.starts_with(right) || right.starts_with(left))
+}
+
+/// Lints user-supplied rules against each other and the built-in detectors
+///
+/// # Arguments
+/// * `builtins` - The built-in detectors the scanner will run; their anchor literals
+///   are their prefixes
+/// * `custom` - The user-supplied rules, in the order they were added
+///
+/// # Returns
+/// * `Vec<RuleLintWarning>` - Every lint found, by rule in the order given
+pub(crate) fn lint_rules(
+    builtins: &[Box<dyn Detector>],
+    custom: &[Arc<dyn Detector>],
+) -> Vec<RuleLintWarning> {
+    let parsed: Vec<Option<Hir>> = custom
+        .iter()
+        .map(|rule| {
+            rule.pattern()
+                .and_then(|pattern| regex_syntax::parse(pattern).ok())
+        })
+        .collect();
+    let prefixes: Vec<Vec<String>> = parsed
+        .iter()
+        .map(|hir| hir.as_ref().map(literal_prefixes).unwrap_or_default())
+        .collect();
+    let builtin_prefixes: Vec<(&str, Vec<String>)> = builtins
+        .iter()
+        .filter_map(|detector| {
+            let anchor = detector.anchor()?;
+            Some((detector.name(), anchor.literals().to_vec()))
+        })
+        .collect();
+
+    let mut warnings = Vec::new();
+    for (index, rule) in custom.iter().enumerate() {
+        let mut warn = |lint| {
+            warnings.push(RuleLintWarning {
+                rule: rule.name().to_string(),
+                lint,
+            })
+        };
+        if let Some(hir) = &parsed[index] {
+            if hir.properties().minimum_len() == Some(0) {
+                warn(RuleLint::EmptyMatch);
+            }
+            if has_unbounded_repetition(hir) {
+                warn(RuleLint::UnboundedRepetition);
+            }
+        }
+        for prefix in &prefixes[index] {
+            let others = custom
+                .iter()
+                .zip(&prefixes)
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .map(|(_, (other, literals))| (other.name(), literals));
+            let rules: Vec<String> = builtin_prefixes
+                .iter()
+                .map(|(name, literals)| (*name, literals))
+                .chain(others)
+                .filter(|(_, literals)| literals.iter().any(|literal| collides(prefix, literal)))
+                .map(|(name, _)| name.to_string())
+                .collect();
+            if !rules.is_empty() {
+                warn(RuleLint::PrefixCollision {
+                    prefix: prefix.clone(),
+                    rules,
+                });
+            }
+        }
+        if let Some(sample) = rule.sample() {
+            if rule.detect(sample).is_empty() {
+                warn(RuleLint::SampleMissed);
+            }
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CustomDetector, Scanner};
+
+    fn lints(rule: CustomDetector) -> Vec<RuleLint> {
+        Scanner::builder()
+            .custom(rule)
+            .validate()
+            .into_iter()
+            .map(|warning| warning.lint)
+            .collect()
+    }
+
+    #[test]
+    fn test_duplicate_stripe_rule_collides_with_the_builtin() {
+        let rule = CustomDetector::new("Acme Stripe Key", r"sk_live_[0-9a-zA-Z]{24}").unwrap();
+        let warnings = Scanner::builder().custom(rule).validate();
+        assert_eq!(
+            warnings,
+            vec![RuleLintWarning {
+                rule: "Acme Stripe Key".to_string(),
+                lint: RuleLint::PrefixCollision {
+                    prefix: "sk_live_".to_string(),
+                    rules: vec!["stripe".to_string()],
+                },
+            }]
+        );
+        assert!(!warnings[0].lint.is_error());
+        assert_eq!(
+            warnings[0].to_string(),
+            "rule `Acme Stripe Key` shares the prefix `sk_live_` with `stripe`"
+        );
+
+        // Not once the built-in is off
+        let rule = CustomDetector::new("Acme Stripe Key", r"sk_live_[0-9a-zA-Z]{24}").unwrap();
+        assert!(Scanner::builder()
+            .disable("stripe")
+            .custom(rule)
+            .validate()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_empty_matches_and_unbounded_classes() {
+        let lints = lints(CustomDetector::new("Loose", r"(?i)secret[=:]?.*").unwrap());
+        assert_eq!(lints, vec![RuleLint::UnboundedRepetition]);
+
+        let lints = self::lints(CustomDetector::new("Anything", r"[a-z0-9]*").unwrap());
+        assert_eq!(lints, vec![RuleLint::EmptyMatch]);
+        assert!(lints[0].is_error());
+    }
+
+    #[test]
+    fn test_clean_rule_passes_and_samples_are_checked() {
+        let rule = CustomDetector::new("Acme Live Key", r"acme_live_[A-Za-z0-9]{40}")
+            .unwrap()
+            .sample(&format!("ACME_KEY=acme_live_{}", "a1B2".repeat(10)));
+        assert!(lints(rule).is_empty());
+
+        let rule = CustomDetector::new("Acme Live Key", r"acme_live_[A-Za-z0-9]{40}")
+            .unwrap()
+            .sample("ACME_KEY=acme_live_tooshort");
+        assert_eq!(lints(rule), vec![RuleLint::SampleMissed]);
+    }
+
+    #[test]
+    fn test_custom_rules_collide_with_each_other() {
+        let warnings = Scanner::builder()
+            .custom(CustomDetector::new("Acme", r"acme_[a-z0-9]{32}").unwrap())
+            .custom(CustomDetector::new("Acme Live", r"acme_live_[a-z0-9]{32}").unwrap())
+            .validate();
+        let rules: Vec<(&str, &RuleLint)> = warnings
+            .iter()
+            .map(|warning| (warning.rule.as_str(), &warning.lint))
+            .collect();
+        assert_eq!(
+            rules,
+            vec![
+                (
+                    "Acme",
+                    &RuleLint::PrefixCollision {
+                        prefix: "acme_".to_string(),
+                        rules: vec!["Acme Live".to_string()],
+                    }
+                ),
+                (
+                    "Acme Live",
+                    &RuleLint::PrefixCollision {
+                        prefix: "acme_live_".to_string(),
+                        rules: vec!["Acme".to_string()],
+                    }
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! Converting byte offsets into the units other languages index strings by
+//!
+//! Spans are byte offsets in Rust and C. Python slices `str` by code point and
+//! JavaScript by UTF-16 code unit, so each binding converts spans before handing them
+//! out. All of them go through [`convert_offsets`], so they can't disagree.
+
+/// What a string offset counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetUnit {
+    /// UTF-8 bytes, as in Rust and C
+    Bytes,
+    /// Unicode code points, as in Python
+    CodePoints,
+    /// UTF-16 code units, as in JavaScript
+    Utf16,
+}
+
+/// Converts byte offsets into `content` to another unit
+///
+/// Offsets may come in any order; `content` is walked once.
+///
+/// # Arguments
+/// * `content` - The string the offsets point into
+/// * `offsets` - Byte offsets, each on a character boundary
+/// * `unit` - The unit to convert to
+///
+/// # Returns
+/// * `Vec<usize>` - The converted offsets, in the order given
+pub fn convert_offsets(content: &str, offsets: &[usize], unit: OffsetUnit) -> Vec<usize> {
+    if unit == OffsetUnit::Bytes {
+        return offsets.to_vec();
+    }
+    let mut order: Vec<usize> = (0..offsets.len()).collect();
+    order.sort_unstable_by_key(|&index| offsets[index]);
+
+    let mut converted = vec![0; offsets.len()];
+    let mut chars = content.chars();
+    let (mut byte, mut units) = (0, 0);
+    for index in order {
+        let target = offsets[index];
+        assert!(
+            content.is_char_boundary(target),
+            "offset {target} is not on a character boundary"
+        );
+        while byte < target {
+            let c = chars.next().expect("offset is within content");
+            byte += c.len_utf8();
+            units += match unit {
+                OffsetUnit::CodePoints => 1,
+                _ => c.len_utf16(),
+            };
+        }
+        converted[index] = units;
+    }
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_offsets_per_unit() {
+        // é is 2 bytes and 1 UTF-16 unit, 🔑 is 4 bytes and 2 UTF-16 units
+        let content = "é🔑 key=abc";
+        let start = content.find("abc").unwrap();
+        let offsets = [start + 3, 0, start];
+        assert_eq!(start, 11);
+        assert_eq!(
+            convert_offsets(content, &offsets, OffsetUnit::Bytes),
+            vec![14, 0, 11]
+        );
+        assert_eq!(
+            convert_offsets(content, &offsets, OffsetUnit::CodePoints),
+            vec![10, 0, 7]
+        );
+        assert_eq!(
+            convert_offsets(content, &offsets, OffsetUnit::Utf16),
+            vec![11, 0, 8]
+        );
+        assert_eq!(
+            convert_offsets(content, &[content.len()], OffsetUnit::CodePoints),
+            vec![content.chars().count()]
+        );
+    }
+}
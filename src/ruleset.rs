@@ -0,0 +1,170 @@
+//! A fixed set of samples for every built-in rule, and a digest of how a scanner treats it
+//!
+//! `fixtures/ruleset_corpus.json` lists, for each built-in detector, samples it must
+//! report and near misses it must not. [`Scanner::behavior_hash`] scans every sample and
+//! hashes the findings, so a release that changes what any rule reports on the corpus
+//! changes the hash. The corpus is embedded, so the check needs no files and runs in a
+//! browser build too.
+//!
+//! The hash of the default scanner is committed as `fixtures/behavior_hash.txt` and
+//! regenerated with `FASTSECRETS_UPDATE_FIXTURES=1 cargo test behavior_hash`.
+
+use std::fmt::Write;
+
+use once_cell::sync::Lazy;
+use serde_json::Value;
+
+use crate::scanner::Scanner;
+use crate::secrets::fingerprint::sha256_hex;
+
+/// Version of the corpus format
+const CORPUS_VERSION: u64 = 1;
+
+/// Samples for one rule of the built-in ruleset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSamples {
+    /// Name of the detector, as given to `secret_types`
+    pub rule: String,
+    /// Inputs the rule reports at least one match in
+    pub positive: Vec<String>,
+    /// Near misses the rule reports nothing in
+    pub negative: Vec<String>,
+}
+
+static CORPUS: Lazy<Vec<RuleSamples>> = Lazy::new(|| {
+    let corpus: Value = serde_json::from_str(include_str!("../fixtures/ruleset_corpus.json"))
+        .expect("the embedded corpus is valid JSON");
+    assert_eq!(corpus["version"], CORPUS_VERSION);
+    let samples = |rule: &Value, field: &str| -> Vec<String> {
+        rule[field]
+            .as_array()
+            .expect("sample lists are arrays")
+            .iter()
+            .map(|sample| sample.as_str().expect("samples are strings").to_string())
+            .collect()
+    };
+    corpus["rules"]
+        .as_array()
+        .expect("`rules` is an array")
+        .iter()
+        .map(|rule| RuleSamples {
+            rule: rule["rule"]
+                .as_str()
+                .expect("rule names are strings")
+                .to_string(),
+            positive: samples(rule, "positive"),
+            negative: samples(rule, "negative"),
+        })
+        .collect()
+});
+
+/// Returns the embedded corpus, one entry per built-in rule in registry order
+///
+/// Integrators can scan it with their own configuration, or compare
+/// [`Scanner::behavior_hash`] with the value they recorded for the release they vetted.
+pub fn ruleset_corpus() -> &'static [RuleSamples] {
+    &CORPUS
+}
+
+impl Scanner {
+    /// Scans the embedded corpus and returns a digest of the findings
+    ///
+    /// Every sample of [`ruleset_corpus`] is scanned with [`Scanner::scan`], in order,
+    /// and the type, span and confidence of each finding go into a SHA-256 hash. The
+    /// same crate version and configuration always give the same hash, on every
+    /// platform; a change to any rule's behavior on the corpus changes it.
+    ///
+    /// # Returns
+    /// * `String` - 64 lowercase hex characters
+    pub fn behavior_hash(&self) -> String {
+        let mut outcomes = String::new();
+        for rule in ruleset_corpus() {
+            let samples = rule
+                .positive
+                .iter()
+                .map(|sample| ("positive", sample))
+                .chain(rule.negative.iter().map(|sample| ("negative", sample)));
+            for (index, (kind, sample)) in samples.enumerate() {
+                let _ = writeln!(outcomes, "{}\t{kind}\t{index}", rule.rule);
+                for found in self.scan(sample) {
+                    let _ = writeln!(
+                        outcomes,
+                        "\t{}\t{}\t{}\t{}",
+                        found.secret_type, found.start, found.end, found.confidence
+                    );
+                }
+            }
+        }
+        sha256_hex(outcomes.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secrets::detector::default_detectors;
+    use std::path::Path;
+
+    /// Path of the committed hash, relative to the crate root
+    const HASH_PATH: &str = "fixtures/behavior_hash.txt";
+
+    #[test]
+    fn test_behavior_hash() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(HASH_PATH);
+        let hash = Scanner::default().behavior_hash();
+        if std::env::var_os("FASTSECRETS_UPDATE_FIXTURES").is_some() {
+            std::fs::write(&path, format!("{hash}\n")).unwrap();
+        }
+        let committed = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            committed.trim_end() == hash,
+            "detection behavior changed on the ruleset corpus; if that is intended, \
+             regenerate {HASH_PATH} with FASTSECRETS_UPDATE_FIXTURES=1 cargo test behavior_hash"
+        );
+
+        // Configuration that changes findings changes the hash
+        let fewer = Scanner::builder().disable("stripe").build().unwrap();
+        assert_ne!(fewer.behavior_hash(), hash);
+    }
+
+    #[test]
+    fn test_every_rule_has_samples_it_reports_and_near_misses_it_does_not() {
+        let detectors = default_detectors();
+        let rules: Vec<&str> = ruleset_corpus()
+            .iter()
+            .map(|rule| rule.rule.as_str())
+            .collect();
+        let names: Vec<&str> = detectors.iter().map(|detector| detector.name()).collect();
+        assert_eq!(
+            rules, names,
+            "the corpus must list every built-in rule, in registry order"
+        );
+
+        for (detector, rule) in detectors.iter().zip(ruleset_corpus()) {
+            assert!(
+                !rule.positive.is_empty(),
+                "no positive sample for {}",
+                rule.rule
+            );
+            assert!(
+                rule.negative.len() >= 2,
+                "too few negative samples for {}",
+                rule.rule
+            );
+            for sample in &rule.positive {
+                assert!(
+                    !detector.detect(sample).is_empty(),
+                    "{} reports nothing in {sample:?}",
+                    rule.rule
+                );
+            }
+            for sample in &rule.negative {
+                assert!(
+                    detector.detect(sample).is_empty(),
+                    "{} reports a match in {sample:?}",
+                    rule.rule
+                );
+            }
+        }
+    }
+}
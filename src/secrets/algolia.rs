@@ -0,0 +1,70 @@
+use once_cell::sync::Lazy;
+
+use super::context::{ContextRule, PLACEHOLDER_KEYWORDS};
+use super::detector::Detector;
+use super::secret_match::SecretMatch;
+use super::secret_type::SecretType;
+
+/// Context rule for Algolia API keys
+/// Keys are 32 lowercase hex characters with no prefix, so "algolia" must appear nearby
+static ALGOLIA_API_KEY_RULE: Lazy<ContextRule> = Lazy::new(|| {
+    ContextRule::new(
+        SecretType::AlgoliaApiKey,
+        r"\b([0-9a-f]{32})\b",
+        &["algolia"],
+    )
+    .expect("Invalid regex pattern")
+    .forbidden(PLACEHOLDER_KEYWORDS)
+});
+
+/// Detects Algolia API keys next to an Algolia keyword
+///
+/// # Arguments
+/// * `content` - The string to check for Algolia API keys
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_algolia_keys(content: &str) -> Vec<SecretMatch> {
+    ALGOLIA_API_KEY_RULE.find_matches(content)
+}
+
+/// Detector registered as `"algolia"`; see [`detect_algolia_keys`]
+pub struct AlgoliaDetector;
+
+impl Detector for AlgoliaDetector {
+    fn name(&self) -> &str {
+        "algolia"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_algolia_keys(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"(?i)algolia".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algolia_admin_key() {
+        let env =
+            "ALGOLIA_APP_ID=HT4XLQ9B2K\nALGOLIA_ADMIN_API_KEY=6be0576ff61c053d5f9a3225e2a90f76\n";
+        let result = detect_algolia_keys(env);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Algolia API Key");
+        assert_eq!(result[0].value, "6be0576ff61c053d5f9a3225e2a90f76");
+    }
+
+    #[test]
+    fn test_hex_without_algolia_context() {
+        assert!(detect_algolia_keys("etag: 6be0576ff61c053d5f9a3225e2a90f76").is_empty());
+        assert!(detect_algolia_keys(
+            "// example: algoliasearch('APPID', '6be0576ff61c053d5f9a3225e2a90f76')"
+        )
+        .is_empty());
+    }
+}
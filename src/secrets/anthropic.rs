@@ -1,6 +1,11 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use super::detector::Detector;
+use super::prescreen::{Anchor, Reach};
+use super::secret_match::{Confidence, SecretMatch};
+use super::secret_type::SecretType;
+
 /// Regex pattern for Anthropic API key detection
 /// Format: sk-ant-[101 characters of alphanumeric, underscore, or hyphen]
 /// Total length: 108 characters (7 for "sk-ant-" + 101 for the key portion)
@@ -15,21 +20,50 @@ static ANTHROPIC_TOKEN_PATTERN: Lazy<Regex> =
 /// * `secret` - The string to check for Anthropic API key patterns
 ///
 /// # Returns
-/// * `Vec<(String, String)>` - List of all (secret_type, value) pairs found
-pub fn detect_anthropic_tokens(secret: &str) -> Vec<(String, String)> {
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_anthropic_tokens(secret: &str) -> Vec<SecretMatch> {
     let mut tokens = Vec::new();
 
     // Use find_iter to find all matches
     for token_match in ANTHROPIC_TOKEN_PATTERN.find_iter(secret) {
-        tokens.push((
-            "Anthropic API Key".to_string(),
-            token_match.as_str().to_string(),
+        tokens.push(SecretMatch::from_match(
+            SecretType::AnthropicApiKey,
+            token_match,
         ));
     }
 
     tokens
 }
 
+/// Detector registered as `"anthropic"`; see [`detect_anthropic_tokens`]
+pub struct AnthropicDetector;
+
+impl Detector for AnthropicDetector {
+    fn name(&self) -> &str {
+        "anthropic"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_anthropic_tokens(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"sk-ant-".to_string())
+    }
+
+    fn anchor(&self) -> Option<Anchor> {
+        Some(Anchor::new(
+            &["sk-ant-"],
+            Reach::Bytes(0),
+            Reach::Bytes("sk-ant-".len() + 101),
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,7 +79,7 @@ mod tests {
         );
         let result = detect_anthropic_tokens(valid_key);
         assert!(!result.is_empty());
-        let (secret_type, value) = result.first().unwrap();
+        let (secret_type, value) = result[0].clone().into_pair();
         assert_eq!(secret_type, "Anthropic API Key");
         assert_eq!(value, valid_key);
     }
@@ -56,7 +90,7 @@ mod tests {
         let code = "ANTHROPIC_API_KEY = 'sk-ant-REDACTED'";
         let result = detect_anthropic_tokens(code);
         assert!(!result.is_empty());
-        let (_, value) = result.first().unwrap();
+        let (_, value) = result[0].clone().into_pair();
         assert!(value.starts_with("sk-ant-"));
         assert_eq!(value.len(), 108);
     }
@@ -75,8 +109,8 @@ mod tests {
         let multi = "key1=sk-ant-REDACTED and key2=sk-ant-REDACTED";
         let results = detect_anthropic_tokens(multi);
         assert_eq!(results.len(), 2);
-        assert_eq!(results[0].0, "Anthropic API Key");
-        assert_eq!(results[1].0, "Anthropic API Key");
+        assert_eq!(results[0].secret_type, "Anthropic API Key");
+        assert_eq!(results[1].secret_type, "Anthropic API Key");
     }
 
     #[test]
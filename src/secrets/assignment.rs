@@ -0,0 +1,358 @@
+use std::ops::Range;
+
+use aho_corasick::AhoCorasick;
+use once_cell::sync::Lazy;
+use regex::{Captures, Match, Regex};
+
+use super::context::{keyword_matcher, PLACEHOLDER_KEYWORDS};
+use super::detector::Detector;
+use super::secret_match::SecretMatch;
+use super::secret_type::SecretType;
+
+/// Identifier parts that mark an assigned value as a secret
+///
+/// Matched against the identifier lowercased, with `-` read as `_`.
+pub const DEFAULT_ASSIGNMENT_KEYWORDS: &[&str] = &[
+    "password",
+    "passwd",
+    "secret",
+    "api_key",
+    "apikey",
+    "access_token",
+    "auth",
+    "client_secret",
+    "private_key",
+];
+
+/// Identifier suffixes that name something about a secret rather than the secret
+const NON_SECRET_SUFFIXES: &[&str] = &[
+    "_env", "_field", "_file", "_hash", "_header", "_id", "_len", "_length", "_name", "_path",
+    "_policy", "_prompt", "_type", "_url", "_var",
+];
+
+/// Values that stand in for a secret in docs, templates and tests
+const PLACEHOLDER_VALUES: &[&str] = &[
+    "changeme",
+    "change_me",
+    "password",
+    "passwd",
+    "secret",
+    "redacted",
+    "todo",
+    "xxx",
+];
+
+/// Bare words that are language literals, not values
+const LITERALS: &[&str] = &["none", "null", "nil", "true", "false", "undefined"];
+
+/// Shortest value reported; anything shorter is a flag or an abbreviation
+const MIN_VALUE_LEN: usize = 4;
+
+/// Regex pattern for `identifier <op> value` assignments
+/// The identifier may be quoted (JSON, Python dicts); the operator is `=`, `:`, `:=`, `=>`
+/// or `==`, which is rejected later as a comparison
+/// Pattern captures: (1) the identifier, (2) the operator, (3) a double-quoted,
+/// (4) a single-quoted or (5) an unquoted value
+static ASSIGNMENT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"["']?([A-Za-z_][A-Za-z0-9_.-]*)["']?[ \t]*(=>|:=|==|=|:)[ \t]*(?:"([^"\r\n]*)"|'([^'\r\n]*)'|([^\s"',;#]+))"#,
+    )
+    .expect("Invalid regex pattern")
+});
+
+/// Checks whether `keyword` appears in `identifier` as a whole word
+///
+/// Words are separated by `_`, `-`, `.`, digits or a lowercase-to-uppercase change, so
+/// `DB_PASSWORD` and `dbPassword` contain "password" but `author` does not contain "auth".
+fn contains_keyword(identifier: &str, normalized: &str, keyword: &str) -> bool {
+    let bytes = identifier.as_bytes();
+    normalized.match_indices(keyword).any(|(start, _)| {
+        let end = start + keyword.len();
+        let starts_word = start == 0
+            || !bytes[start - 1].is_ascii_alphabetic()
+            || (bytes[start - 1].is_ascii_lowercase() && bytes[start].is_ascii_uppercase());
+        let ends_word = end == bytes.len()
+            || !bytes[end].is_ascii_alphabetic()
+            || (bytes[end - 1].is_ascii_lowercase() && bytes[end].is_ascii_uppercase());
+        starts_word && ends_word
+    })
+}
+
+/// Checks whether a value is a reference to a secret kept elsewhere
+///
+/// Covers shell, Make and Windows variables, template expressions, and unquoted code
+/// such as `os.environ["DB_PASSWORD"]`, `getenv("KEY")` or `settings.SECRET_KEY`.
+fn is_reference(value: &str, quoted: bool) -> bool {
+    if value.starts_with('$')
+        || value.starts_with('%')
+        || value.starts_with("{{")
+        || value.starts_with("#{")
+    {
+        return true;
+    }
+    if quoted {
+        return false;
+    }
+    let is_path = value.contains('.')
+        && value
+            .split('.')
+            .all(|part| part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_'));
+    value.contains('(') || value.contains('[') || is_path
+}
+
+/// Checks whether a value is empty, a literal or an obvious placeholder
+///
+/// Values made only of lowercase words, like `basic` or `oauth_token`, name a setting
+/// rather than hold a secret, and are skipped too, as is prose with spaces in it.
+fn is_placeholder(value: &str) -> bool {
+    let lower = value.to_ascii_lowercase();
+    value.chars().count() < MIN_VALUE_LEN
+        || value.contains(char::is_whitespace)
+        || value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c == '_' || c == '-')
+        || (value.starts_with('<') && value.ends_with('>'))
+        || value.chars().all(|c| c == '*' || c == 'x' || c == 'X')
+        || LITERALS.contains(&lower.as_str())
+        || PLACEHOLDER_VALUES.contains(&lower.as_str())
+        || lower.starts_with("your")
+        || PLACEHOLDER_KEYWORDS
+            .iter()
+            .any(|keyword| lower.contains(keyword))
+}
+
+/// Reports values assigned to secret-looking identifiers, e.g. `password = "hunter2"`
+///
+/// Covers `=`, `:`, `:=` and `=>` assignments with quoted or unquoted values, as written
+/// in Python, YAML, JSON, shell, Ruby and Go, and `Key=value` pairs inside a quoted
+/// connection string, like the `Password=` of an ADO.NET `connectionString`. Only the
+/// value is reported. Empty values,
+/// variable references and placeholders are skipped, and so are values a vendor detector
+/// already reports (see [`dedupe`](super::dedupe::dedupe)).
+pub struct KeywordAssignmentDetector {
+    keywords: Vec<String>,
+    prefilter: AhoCorasick,
+}
+
+impl Default for KeywordAssignmentDetector {
+    fn default() -> Self {
+        Self::with_keywords(DEFAULT_ASSIGNMENT_KEYWORDS.iter().map(|k| k.to_string()))
+    }
+}
+
+impl KeywordAssignmentDetector {
+    fn with_keywords(keywords: impl IntoIterator<Item = String>) -> Self {
+        let mut keywords: Vec<String> = keywords
+            .into_iter()
+            .map(|keyword| keyword.to_ascii_lowercase().replace('-', "_"))
+            .collect();
+        keywords.sort();
+        keywords.dedup();
+        // Identifiers may spell `_` as `-`, so the prefilter looks for both
+        let mut spellings: Vec<String> = keywords.clone();
+        spellings.extend(keywords.iter().map(|keyword| keyword.replace('_', "-")));
+        let spellings: Vec<&str> = spellings.iter().map(String::as_str).collect();
+        KeywordAssignmentDetector {
+            prefilter: keyword_matcher(&spellings),
+            keywords,
+        }
+    }
+
+    /// Adds identifier keywords to the default list, e.g. `"db_pass"`
+    pub fn keywords(self, extra: &[&str]) -> Self {
+        Self::with_keywords(
+            self.keywords
+                .into_iter()
+                .chain(extra.iter().map(|keyword| keyword.to_string())),
+        )
+    }
+
+    /// Checks whether an identifier names a secret
+    fn is_secret_identifier(&self, identifier: &str) -> bool {
+        let normalized = identifier.to_ascii_lowercase().replace('-', "_");
+        !has_non_secret_suffix(&normalized)
+            && self
+                .keywords
+                .iter()
+                .any(|keyword| contains_keyword(identifier, &normalized, keyword))
+    }
+
+    /// Finds all values assigned to secret-looking identifiers
+    ///
+    /// # Arguments
+    /// * `content` - The string to check
+    ///
+    /// # Returns
+    /// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+    pub fn find_matches(&self, content: &str) -> Vec<SecretMatch> {
+        if !self.prefilter.is_match(content) {
+            return Vec::new();
+        }
+
+        assigned_values(content)
+            .filter(|(identifier, _)| self.is_secret_identifier(identifier))
+            .map(|(_, value)| SecretMatch::from_match(SecretType::GenericSecret, value))
+            .collect()
+    }
+}
+
+/// Checks whether a lowercased, `_`-separated identifier names something about a secret
+pub(super) fn has_non_secret_suffix(normalized: &str) -> bool {
+    NON_SECRET_SUFFIXES
+        .iter()
+        .any(|suffix| normalized.ends_with(suffix))
+}
+
+/// Finds the assignments inside a quoted value that holds `;`-separated pairs, such as
+/// `"Server=db;User Id=app;Password=hunter2;"`
+///
+/// # Arguments
+/// * `content` - The whole input, so the matches' offsets index into it
+/// * `value` - Where the quoted value is in `content`
+///
+/// # Returns
+/// * `Vec<Captures>` - Captures of [`ASSIGNMENT_PATTERN`] that lie within the value
+fn connection_string_assignments(content: &str, value: Range<usize>) -> Vec<Captures<'_>> {
+    let mut found = Vec::new();
+    let mut at = value.start;
+    while let Some(captures) = ASSIGNMENT_PATTERN.captures_at(content, at) {
+        let whole = captures.get(0).expect("group 0 always matches");
+        if whole.end() > value.end {
+            break;
+        }
+        at = whole.end();
+        found.push(captures);
+    }
+    found
+}
+
+/// Finds every `identifier <op> value` assignment whose value could be a secret
+///
+/// Quoted values holding `;`-separated pairs are searched for assignments too.
+/// Comparisons, variable references and placeholders are skipped.
+///
+/// # Arguments
+/// * `content` - The string to check
+///
+/// # Returns
+/// * An iterator of (identifier, value match) pairs, in input order
+pub(super) fn assigned_values(content: &str) -> impl Iterator<Item = (&str, Match<'_>)> {
+    ASSIGNMENT_PATTERN
+        .captures_iter(content)
+        .flat_map(move |captures| {
+            let inner = match captures.get(3).or(captures.get(4)) {
+                Some(value) if value.as_str().contains(';') => {
+                    connection_string_assignments(content, value.range())
+                }
+                _ => Vec::new(),
+            };
+            std::iter::once(captures).chain(inner)
+        })
+        .filter(|captures| &captures[2] != "==")
+        .filter_map(|captures| {
+            let identifier = captures.get(1)?.as_str();
+            let (value, quoted) = match (captures.get(3).or(captures.get(4)), captures.get(5)) {
+                (Some(value), _) => (value, true),
+                (None, Some(value)) => (value, false),
+                (None, None) => return None,
+            };
+            if is_reference(value.as_str(), quoted) || is_placeholder(value.as_str()) {
+                return None;
+            }
+            Some((identifier, value))
+        })
+}
+
+/// Detector registered as `"keyword_assignment"`; see [`KeywordAssignmentDetector`]
+impl Detector for KeywordAssignmentDetector {
+    fn name(&self) -> &str {
+        "keyword_assignment"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        self.find_matches(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        let spellings: Vec<String> = self
+            .keywords
+            .iter()
+            .flat_map(|keyword| [keyword.clone(), keyword.replace('_', "-")])
+            .map(|spelling| regex::escape(&spelling))
+            .collect();
+        Some(format!("(?i){}", spellings.join("|")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(content: &str) -> Vec<String> {
+        KeywordAssignmentDetector::default()
+            .find_matches(content)
+            .into_iter()
+            .map(|found| found.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_python_assignments() {
+        let content = r#"
+DB_PASSWORD = "hunter2!"
+api_key='abc123xyz'
+config = {"client_secret": "s3cr3t-value", 'auth': 'tok_9f8e7d'}
+password = os.environ["DB_PASSWORD"]
+secret = os.getenv("SECRET")
+apiKey = settings.API_KEY
+if password == "hunter2!":
+    author = "Jane Doe"
+"#;
+        assert_eq!(
+            values(content),
+            vec!["hunter2!", "abc123xyz", "s3cr3t-value", "tok_9f8e7d"]
+        );
+    }
+
+    #[test]
+    fn test_yaml_assignments() {
+        let content = "database:\n  password: Tr0ub4dor&3\n  api-key: 'q9w8e7r6'\n\
+            smtp:\n  password:\n  secret: ${SMTP_SECRET}\n  password_file: /run/secrets/smtp\n";
+        assert_eq!(values(content), vec!["Tr0ub4dor&3", "q9w8e7r6"]);
+    }
+
+    #[test]
+    fn test_json_assignments() {
+        let content = r#"{"access_token": "ya29.a0AfH6SMB", "private_key": "", "password": "<password>", "secret": "changeme"}"#;
+        let found = KeywordAssignmentDetector::default().find_matches(content);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].value, "ya29.a0AfH6SMB");
+        assert_eq!(&content[found[0].start..found[0].end], "ya29.a0AfH6SMB");
+        assert_eq!(found[0].secret_type.to_string(), "Generic Secret");
+    }
+
+    #[test]
+    fn test_shell_assignments() {
+        let content = "export API_KEY=abc123xyz\nMYSQL_PWD=x\nPASSWD=\"$(cat /run/pw)\"\n\
+            ./deploy --password=p4ssw0rd-live\nAUTH_TOKEN=%AUTH_TOKEN%\nSECRET=your-secret-here\n";
+        assert_eq!(values(content), vec!["abc123xyz", "p4ssw0rd-live"]);
+    }
+
+    #[test]
+    fn test_connection_string_passwords() {
+        let content = r#"<add name="Orders" connectionString="Server=sql-prod-02;User Id=orders_app;Password=Vx7qLm2pR9tZw4Kd;Encrypt=True" />
+$conn = 'Data Source=db;Initial Catalog=app;Integrated Security=SSPI;'
+"#;
+        let found = KeywordAssignmentDetector::default().find_matches(content);
+        assert_eq!(found.len(), 1);
+        assert_eq!(&content[found[0].start..found[0].end], "Vx7qLm2pR9tZw4Kd");
+    }
+
+    #[test]
+    fn test_keywords_are_extensible() {
+        let content = "db_pass = \"hunter2!\"\n";
+        assert!(values(content).is_empty());
+        let detector = KeywordAssignmentDetector::default().keywords(&["DB-PASS"]);
+        assert_eq!(detector.find_matches(content)[0].value, "hunter2!");
+    }
+}
@@ -0,0 +1,270 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::detector::Detector;
+use super::secret_match::SecretMatch;
+use super::secret_type::SecretType;
+
+/// Regex pattern for the oauth2 block of a browser extension manifest.json
+/// Captures the client_secret value inside the "oauth2" object
+static MANIFEST_CLIENT_SECRET_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#""oauth2"\s*:\s*\{[^{}]*?"client_secret"\s*:\s*"([^"\s]+)""#)
+        .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for the start of a chrome-webstore-upload CLI invocation
+static CHROME_WEBSTORE_COMMAND_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"chrome-webstore-upload(?:-cli)?\b").expect("Invalid regex pattern"));
+
+/// Regex pattern for the start of a web-ext sign invocation (Firefox AMO)
+static WEB_EXT_SIGN_COMMAND_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"web-ext\s+sign\b").expect("Invalid regex pattern"));
+
+/// Regex patterns for chrome-webstore-upload credential flags
+/// Pattern captures: (1) the flag value, optionally quoted
+static CHROME_WEBSTORE_FLAG_PATTERNS: Lazy<Vec<(SecretType, Regex)>> = Lazy::new(|| {
+    vec![
+        (
+            SecretType::ChromeWebStoreClientSecret,
+            Regex::new(r#"--client-secret(?:=|\s+)['"]?([^\s'"]+)"#)
+                .expect("Invalid regex pattern"),
+        ),
+        (
+            SecretType::ChromeWebStoreRefreshToken,
+            Regex::new(r#"--refresh-token(?:=|\s+)['"]?([^\s'"]+)"#)
+                .expect("Invalid regex pattern"),
+        ),
+    ]
+});
+
+/// Regex pattern for the web-ext sign --api-secret flag
+/// AMO JWT secrets are 64 hex characters
+static WEB_EXT_API_SECRET_FLAG_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"--api-secret(?:=|\s+)['"]?([0-9a-f]{64})\b"#).expect("Invalid regex pattern")
+});
+
+/// Regex pattern for the WEB_EXT_API_SECRET environment variable read by web-ext
+static WEB_EXT_API_SECRET_ENV_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"WEB_EXT_API_SECRET['"]?\s*[=:]\s*['"]?([0-9a-f]{64})\b"#)
+        .expect("Invalid regex pattern")
+});
+
+/// Returns the text of every command starting at a match of `command_pattern`
+///
+/// A command runs until the end of the line, following shell line continuations
+/// (a trailing backslash) so flags split over several lines are included.
+///
+/// # Arguments
+/// * `content` - The string to search
+/// * `command_pattern` - Regex matching the start of the command
+///
+/// # Returns
+/// * `Vec<&str>` - The command text for each invocation found
+fn command_spans<'a>(content: &'a str, command_pattern: &Regex) -> Vec<&'a str> {
+    let mut spans = Vec::new();
+
+    for command_match in command_pattern.find_iter(content) {
+        let mut end = command_match.end();
+        loop {
+            match content[end..].find('\n') {
+                Some(newline) => {
+                    let line_end = end + newline;
+                    let continued = content[..line_end].trim_end_matches('\r').ends_with('\\');
+                    end = line_end + 1;
+                    if !continued {
+                        end = line_end;
+                        break;
+                    }
+                }
+                None => {
+                    end = content.len();
+                    break;
+                }
+            }
+        }
+        spans.push(&content[command_match.start()..end]);
+    }
+
+    spans
+}
+
+/// Checks whether a flag value is a variable reference rather than a literal
+fn is_variable_reference(value: &str) -> bool {
+    value.starts_with('$') || value.starts_with('%')
+}
+
+/// Detects browser extension store publishing secrets and manifest OAuth secrets
+///
+/// Covers:
+/// - `oauth2.client_secret` in extension manifest.json files
+/// - chrome-webstore-upload `--client-secret` / `--refresh-token` flags
+/// - Firefox AMO `web-ext sign --api-secret` flags and WEB_EXT_API_SECRET assignments
+///
+/// Flags whose value is a variable reference (`$SECRET`, `${{ secrets.X }}`) are ignored.
+///
+/// # Arguments
+/// * `content` - The string to check for browser extension secrets
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_browser_extension_secrets(content: &str) -> Vec<SecretMatch> {
+    let mut secrets = Vec::new();
+
+    for captures in MANIFEST_CLIENT_SECRET_PATTERN.captures_iter(content) {
+        if let Some(secret_match) = captures.get(1) {
+            secrets.push(SecretMatch::from_match(
+                SecretType::BrowserExtensionOAuthClientSecret,
+                secret_match,
+            ));
+        }
+    }
+
+    for command in command_spans(content, &CHROME_WEBSTORE_COMMAND_PATTERN) {
+        for (secret_type, pattern) in CHROME_WEBSTORE_FLAG_PATTERNS.iter() {
+            for captures in pattern.captures_iter(command) {
+                if let Some(value) = captures.get(1) {
+                    if !is_variable_reference(value.as_str()) {
+                        secrets.push(SecretMatch::from_slice(
+                            secret_type.clone(),
+                            content,
+                            value.as_str(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    for command in command_spans(content, &WEB_EXT_SIGN_COMMAND_PATTERN) {
+        for captures in WEB_EXT_API_SECRET_FLAG_PATTERN.captures_iter(command) {
+            if let Some(value) = captures.get(1) {
+                secrets.push(SecretMatch::from_slice(
+                    SecretType::FirefoxAddonsApiSecret,
+                    content,
+                    value.as_str(),
+                ));
+            }
+        }
+    }
+
+    for captures in WEB_EXT_API_SECRET_ENV_PATTERN.captures_iter(content) {
+        if let Some(value) = captures.get(1) {
+            secrets.push(SecretMatch::from_match(
+                SecretType::FirefoxAddonsApiSecret,
+                value,
+            ));
+        }
+    }
+
+    secrets
+}
+
+/// Detector registered as `"browser_extension"`; see [`detect_browser_extension_secrets`]
+pub struct BrowserExtensionDetector;
+
+impl Detector for BrowserExtensionDetector {
+    fn name(&self) -> &str {
+        "browser_extension"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_browser_extension_secrets(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r#""oauth2"|chrome-webstore-upload|web-ext|WEB_EXT_API_SECRET"#.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AMO_SECRET: &str = "3f2a9c1e7b4d8f6a0c5e2b9d7f1a3c6e8b0d4f2a7c9e1b5d3f6a8c0e2b4d6f8a";
+
+    #[test]
+    fn test_manifest_oauth2_client_secret() {
+        let manifest = r#"{
+  "manifest_version": 3,
+  "name": "My Extension",
+  "oauth2": {
+    "client_id": "123456789-abc.apps.googleusercontent.com",
+    "client_secret": "GOCSPX-AbCdEfGhIjKlMnOpQrStUvWx",
+    "scopes": ["https://www.googleapis.com/auth/drive"]
+  }
+}"#;
+        let result = detect_browser_extension_secrets(manifest);
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].secret_type,
+            "Browser Extension OAuth Client Secret"
+        );
+        assert_eq!(result[0].value, "GOCSPX-AbCdEfGhIjKlMnOpQrStUvWx");
+    }
+
+    #[test]
+    fn test_chrome_webstore_upload_publish_workflow() {
+        let workflow = r#"jobs:
+  publish:
+    runs-on: ubuntu-latest
+    steps:
+      - run: |
+          npx chrome-webstore-upload-cli upload --source extension.zip \
+            --extension-id abcdefghijklmnopabcdefghijklmnop \
+            --client-id 123456789-abc.apps.googleusercontent.com \
+            --client-secret GOCSPX-AbCdEfGhIjKlMnOpQrStUvWx \
+            --refresh-token 1//0gAbCdEfGhIjKlMnOpQrStUvWxYz
+"#;
+        let result = detect_browser_extension_secrets(workflow);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].secret_type, "Chrome Web Store Client Secret");
+        assert_eq!(result[0].value, "GOCSPX-AbCdEfGhIjKlMnOpQrStUvWx");
+        assert_eq!(result[1].secret_type, "Chrome Web Store Refresh Token");
+        assert_eq!(result[1].value, "1//0gAbCdEfGhIjKlMnOpQrStUvWxYz");
+    }
+
+    #[test]
+    fn test_chrome_webstore_upload_with_secret_references() {
+        let workflow = r#"      - run: npx chrome-webstore-upload-cli upload --client-secret ${{ secrets.CLIENT_SECRET }} --refresh-token $REFRESH_TOKEN"#;
+        assert!(detect_browser_extension_secrets(workflow).is_empty());
+    }
+
+    #[test]
+    fn test_firefox_web_ext_sign_publish_workflow() {
+        let workflow = format!(
+            "      - run: npx web-ext sign --channel listed --api-key user:12345678:901 --api-secret {AMO_SECRET}\n"
+        );
+        let result = detect_browser_extension_secrets(&workflow);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Firefox Add-ons API Secret");
+        assert_eq!(result[0].value, AMO_SECRET);
+    }
+
+    #[test]
+    fn test_firefox_web_ext_api_secret_env() {
+        let env = format!("    env:\n      WEB_EXT_API_KEY: user:12345678:901\n      WEB_EXT_API_SECRET: {AMO_SECRET}\n");
+        let result = detect_browser_extension_secrets(&env);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].value, AMO_SECRET);
+    }
+
+    #[test]
+    fn test_api_secret_outside_web_ext_sign_not_reported() {
+        let command = format!("some-other-tool --api-secret {AMO_SECRET}");
+        assert!(detect_browser_extension_secrets(&command).is_empty());
+    }
+
+    #[test]
+    fn test_flags_on_following_command_not_included() {
+        let script = "chrome-webstore-upload upload --extension-id abc\nother-tool --client-secret literalvalue";
+        assert!(detect_browser_extension_secrets(script).is_empty());
+    }
+
+    #[test]
+    fn test_no_browser_extension_secrets() {
+        assert!(detect_browser_extension_secrets("").is_empty());
+        assert!(
+            detect_browser_extension_secrets(r#"{"name": "ext", "version": "1.0"}"#).is_empty()
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::detector::Detector;
+use super::graphql::header_credential;
+use super::secret_match::SecretMatch;
+use super::secret_type::SecretType;
+
+/// Regex pattern for TOML table headers
+/// Pattern captures: (1) the table name
+static TOML_TABLE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\[\s*([A-Za-z0-9_.\x22-]+)\s*\]").expect("Invalid regex pattern"));
+
+/// Regex pattern for a `token = "..."` entry, optionally with a dotted key prefix
+/// Pattern captures: (1) the key path before `token`, if any, (2) the token
+static TOML_TOKEN_ENTRY_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"^(?:([A-Za-z0-9_.-]+)\.)?token\s*=\s*["']([^"']+)["']"#)
+        .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for Bazel header flags in .bazelrc files and command lines
+/// Pattern captures: (1) the header name, (2) the header value
+static BAZEL_HEADER_FLAG_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"--(?:remote_header|remote_cache_header|remote_exec_header|remote_downloader_header|bes_header)=['"]?([A-Za-z0-9_-]+)=([^'"\n]+)"#,
+    )
+    .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for `//go:generate` directives
+/// Pattern captures: (1) the directive command line
+static GO_GENERATE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^\s*//go:generate\s+(.+)$").expect("Invalid regex pattern"));
+
+/// Regex pattern for Makefile recipe lines, which start with a tab
+/// Pattern captures: (1) the recipe command line
+static MAKE_RECIPE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^\t(.+)$").expect("Invalid regex pattern"));
+
+/// Regex pattern for credentials passed on a build command line
+/// Matches token/key/password flags and Authorization headers
+/// Pattern captures: (1) the credential
+static COMMAND_CREDENTIAL_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)(?:\s-{1,2}(?:token|api[-_]?key|password|secret)[= ]|authorization:\s*(?:bearer|token|basic)\s+)['"]?([^\s'"]+)"#,
+    )
+    .expect("Invalid regex pattern")
+});
+
+/// Checks whether a value is a Make, shell or build variable reference
+fn is_variable_reference(value: &str) -> bool {
+    value.starts_with('$') || value.starts_with("%{") || value.starts_with("{{")
+}
+
+/// Checks whether a TOML table or dotted key names a Cargo registry
+fn is_cargo_registry_table(table: &str) -> bool {
+    table == "registry" || table.starts_with("registries.")
+}
+
+/// Detects registry tokens in Cargo config.toml files
+///
+/// Tracks the current TOML table so that only `token` keys under `[registry]` or
+/// `[registries.<name>]` (or the equivalent dotted keys) are reported.
+fn detect_cargo_registry_tokens(content: &str) -> Vec<SecretMatch> {
+    let mut tokens = Vec::new();
+    let mut table = String::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(captures) = TOML_TABLE_PATTERN.captures(line) {
+            table = captures[1].replace('"', "");
+            continue;
+        }
+        let Some(captures) = TOML_TOKEN_ENTRY_PATTERN.captures(line) else {
+            continue;
+        };
+        let key_path = match (table.as_str(), captures.get(1)) {
+            ("", Some(prefix)) => prefix.as_str().replace('"', ""),
+            (table, Some(prefix)) => format!("{table}.{}", prefix.as_str().replace('"', "")),
+            (table, None) => table.to_string(),
+        };
+        if is_cargo_registry_table(&key_path) {
+            tokens.push(SecretMatch::from_slice(
+                SecretType::CargoRegistryToken,
+                content,
+                &captures[2],
+            ));
+        }
+    }
+
+    tokens
+}
+
+/// Detects secrets in build configuration and build scripts
+///
+/// Covers:
+/// - Cargo `config.toml` registry tokens under `[registry]` and `[registries.<name>]`
+/// - Bazel `--remote_header` (and related header flag) values in .bazelrc files
+/// - Token, key and password flags or Authorization headers in `//go:generate`
+///   directives and Makefile recipe lines
+///
+/// Make, shell and build variable references such as `$(TOKEN)` are not reported.
+/// Credentials in `[http] proxy` URLs are reported by the basic_auth detector.
+///
+/// # Arguments
+/// * `content` - The string to check for build configuration secrets
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_build_config_secrets(content: &str) -> Vec<SecretMatch> {
+    let mut secrets = detect_cargo_registry_tokens(content);
+
+    for captures in BAZEL_HEADER_FLAG_PATTERN.captures_iter(content) {
+        let value = header_credential(&captures[2]);
+        if !value.is_empty() && !is_variable_reference(value) {
+            secrets.push(SecretMatch::from_slice(
+                SecretType::BazelRemoteHeaderSecret,
+                content,
+                value,
+            ));
+        }
+    }
+
+    for (pattern, label) in [
+        (&*GO_GENERATE_PATTERN, SecretType::GoGenerateDirectiveSecret),
+        (&*MAKE_RECIPE_PATTERN, SecretType::MakefileRecipeSecret),
+    ] {
+        for command in pattern.captures_iter(content) {
+            let Some(command) = command.get(1) else {
+                continue;
+            };
+            for captures in COMMAND_CREDENTIAL_PATTERN.captures_iter(command.as_str()) {
+                let Some(value) = captures.get(1) else {
+                    continue;
+                };
+                if !is_variable_reference(value.as_str()) {
+                    secrets.push(
+                        SecretMatch::from_match(label.clone(), value).offset(command.start()),
+                    );
+                }
+            }
+        }
+    }
+
+    secrets
+}
+
+/// Detector registered as `"build_config"`; see [`detect_build_config_secrets`]
+pub struct BuildConfigDetector;
+
+impl Detector for BuildConfigDetector {
+    fn name(&self) -> &str {
+        "build_config"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_build_config_secrets(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"(?i)token|api[-_]?key|password|secret|authorization|_header=".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cargo_config_private_registry_token() {
+        let config = r#"[registries.internal]
+index = "sparse+https://cargo.internal.example.com/index/"
+token = "cio_9f8e7d6c5b4a39281706f5e4d3c2b1a0"
+
+[net]
+git-fetch-with-cli = true
+"#;
+        let result = detect_build_config_secrets(config);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Cargo Registry Token");
+        assert_eq!(result[0].value, "cio_9f8e7d6c5b4a39281706f5e4d3c2b1a0");
+    }
+
+    #[test]
+    fn test_cargo_config_dotted_keys() {
+        let config =
+            "registry.token = \"cioAbCdEf0123456789\"\nregistries.mirror.token = \"mirrorTok3n\"\n";
+        let result = detect_build_config_secrets(config);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].value, "cioAbCdEf0123456789");
+        assert_eq!(result[1].value, "mirrorTok3n");
+    }
+
+    #[test]
+    fn test_token_outside_registry_table_not_reported() {
+        let config = "[github]\ntoken = \"not-a-cargo-token\"\n";
+        assert!(detect_build_config_secrets(config).is_empty());
+    }
+
+    #[test]
+    fn test_bazelrc_remote_header() {
+        let bazelrc = "build --remote_cache=grpcs://cache.example.com\nbuild --remote_header=x-api-key=Bz1x9Kq4Lm7Np2Rs5Tv8Wy3\nbuild --bes_header=Authorization=Bearer Bes7Tok3nValue\n";
+        let result = detect_build_config_secrets(bazelrc);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].secret_type, "Bazel Remote Header Secret");
+        assert_eq!(result[0].value, "Bz1x9Kq4Lm7Np2Rs5Tv8Wy3");
+        assert_eq!(result[1].value, "Bes7Tok3nValue");
+    }
+
+    #[test]
+    fn test_bazel_header_value_with_trailing_text() {
+        let bazelrc = "build --remote_header=x-api-key=Bz1x9Kq4Lm7Np2Rs5\t# staging\nbuild --bes_header=Authorization=Bearer\u{a0}Bes7Tok3nValue\r\n";
+        let result = detect_build_config_secrets(bazelrc);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].value, "Bz1x9Kq4Lm7Np2Rs5");
+        assert_eq!(result[1].value, "Bes7Tok3nValue");
+    }
+
+    #[test]
+    fn test_go_generate_directive() {
+        let source = "package api\n\n//go:generate oapi-codegen -generate types -token=G3nT0kenValue openapi.yaml\n";
+        let result = detect_build_config_secrets(source);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Go Generate Directive Secret");
+        assert_eq!(result[0].value, "G3nT0kenValue");
+    }
+
+    #[test]
+    fn test_makefile_hardcoded_header() {
+        let makefile = "release:\n\tcurl -H \"Authorization: token ghx_Hardc0dedValue\" https://api.example.com/releases\n";
+        let result = detect_build_config_secrets(makefile);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Makefile Recipe Secret");
+        assert_eq!(result[0].value, "ghx_Hardc0dedValue");
+    }
+
+    #[test]
+    fn test_makefile_variable_references_not_reported() {
+        let makefile = "TOKEN ?= $(shell cat .token)\n\nrelease:\n\tcurl -H \"Authorization: token $(SECRET)\" https://api.example.com/releases\n\tgh release create --token=${GITHUB_TOKEN} v1.0.0\n";
+        assert!(detect_build_config_secrets(makefile).is_empty());
+    }
+}
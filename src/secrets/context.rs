@@ -0,0 +1,319 @@
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder};
+use regex::Regex;
+
+use super::detector::Detector;
+use super::secret_match::{Confidence, SecretMatch};
+use super::secret_type::SecretType;
+
+/// Returns the text surrounding a match, widened to valid UTF-8 boundaries
+///
+/// # Arguments
+/// * `content` - The full string that was scanned
+/// * `start` - Byte offset where the match starts
+/// * `end` - Byte offset where the match ends
+/// * `radius` - Number of bytes to include on either side of the match
+///
+/// # Returns
+/// * `&str` - The match plus up to `radius` bytes before and after it
+pub fn context_window(content: &str, start: usize, end: usize, radius: usize) -> &str {
+    let mut window_start = start.saturating_sub(radius);
+    while !content.is_char_boundary(window_start) {
+        window_start -= 1;
+    }
+    let mut window_end = end.saturating_add(radius).min(content.len());
+    while !content.is_char_boundary(window_end) {
+        window_end += 1;
+    }
+
+    &content[window_start..window_end]
+}
+
+/// Keywords that mark a value as documentation rather than a live credential
+pub const PLACEHOLDER_KEYWORDS: &[&str] = &["example", "sample", "dummy", "placeholder"];
+
+/// How far a keyword may be from a value in a context rule
+#[derive(Clone, Copy, Debug)]
+pub enum ContextWindow {
+    /// At most this many characters between the keyword and the value
+    Chars(usize),
+    /// At most this many line breaks between the keyword and the value (0 = same line)
+    Lines(usize),
+}
+
+/// Which side of a value a required keyword must appear on
+#[derive(Clone, Copy, Debug)]
+pub enum ContextDirection {
+    /// The keyword must appear before the value
+    Before,
+    /// The keyword must appear after the value
+    After,
+    /// The keyword may appear on either side
+    Either,
+}
+
+/// A declarative "value near keyword" rule
+///
+/// A rule reports every match of its value pattern that has one of the required
+/// keywords within the window on the configured side, and none of the forbidden
+/// keywords within the window on either side or inside the value. Keywords are
+/// matched case-insensitively with Aho-Corasick, and the value pattern is only run
+/// when a required keyword occurs somewhere in the input.
+pub struct ContextRule {
+    secret_type: SecretType,
+    value_pattern: Regex,
+    required: AhoCorasick,
+    forbidden: Option<AhoCorasick>,
+    window: ContextWindow,
+    direction: ContextDirection,
+}
+
+/// Builds a case-insensitive keyword matcher
+pub fn keyword_matcher(keywords: &[&str]) -> AhoCorasick {
+    AhoCorasickBuilder::new()
+        .ascii_case_insensitive(true)
+        .build(keywords)
+        .expect("Invalid keyword set")
+}
+
+/// Returns the byte spans of all keyword occurrences, in order
+fn keyword_spans(matcher: &AhoCorasick, content: &str) -> Vec<(usize, usize)> {
+    matcher
+        .find_iter(content)
+        .map(|keyword| (keyword.start(), keyword.end()))
+        .collect()
+}
+
+impl ContextRule {
+    /// Creates a rule with a 64 character window on either side of the value
+    ///
+    /// # Arguments
+    /// * `secret_type` - Label reported for matches
+    /// * `value_pattern` - Regex for the value; capture group 1 is reported if present
+    /// * `required_keywords` - Keywords of which at least one must be near the value
+    ///
+    /// # Returns
+    /// * `Result<ContextRule, regex::Error>` - The rule, or the value pattern's compile error
+    pub fn new(
+        secret_type: SecretType,
+        value_pattern: &str,
+        required_keywords: &[&str],
+    ) -> Result<Self, regex::Error> {
+        Ok(ContextRule {
+            secret_type,
+            value_pattern: Regex::new(value_pattern)?,
+            required: keyword_matcher(required_keywords),
+            forbidden: None,
+            window: ContextWindow::Chars(64),
+            direction: ContextDirection::Either,
+        })
+    }
+
+    /// Sets how far a keyword may be from the value
+    pub fn window(mut self, window: ContextWindow) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Sets which side of the value a required keyword must appear on
+    pub fn direction(mut self, direction: ContextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets keywords that suppress a match when they appear near or inside the value
+    pub fn forbidden(mut self, keywords: &[&str]) -> Self {
+        self.forbidden = Some(keyword_matcher(keywords));
+        self
+    }
+
+    /// Checks whether the text between a keyword and a value fits in the window
+    fn fits_window(&self, gap: &str) -> bool {
+        match self.window {
+            ContextWindow::Chars(max) => gap.chars().count() <= max,
+            ContextWindow::Lines(max) => gap.matches('\n').count() <= max,
+        }
+    }
+
+    /// Checks whether any span lies within the window before and/or after a value
+    fn has_span_near(
+        &self,
+        content: &str,
+        spans: &[(usize, usize)],
+        start: usize,
+        end: usize,
+        direction: ContextDirection,
+    ) -> bool {
+        spans.iter().any(|&(keyword_start, keyword_end)| {
+            let before = keyword_end <= start && self.fits_window(&content[keyword_end..start]);
+            let after = keyword_start >= end && self.fits_window(&content[end..keyword_start]);
+            match direction {
+                ContextDirection::Before => before,
+                ContextDirection::After => after,
+                ContextDirection::Either => before || after,
+            }
+        })
+    }
+
+    /// Finds all values matching this rule
+    ///
+    /// # Arguments
+    /// * `content` - The string to check
+    ///
+    /// # Returns
+    /// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+    pub fn find_matches(&self, content: &str) -> Vec<SecretMatch> {
+        let required_spans = keyword_spans(&self.required, content);
+        if required_spans.is_empty() {
+            return Vec::new();
+        }
+        let forbidden_spans = self
+            .forbidden
+            .as_ref()
+            .map(|matcher| keyword_spans(matcher, content))
+            .unwrap_or_default();
+
+        let mut matches = Vec::new();
+        for captures in self.value_pattern.captures_iter(content) {
+            let Some(value) = captures.get(1).or_else(|| captures.get(0)) else {
+                continue;
+            };
+            let (start, end) = (value.start(), value.end());
+            if !self.has_span_near(content, &required_spans, start, end, self.direction) {
+                continue;
+            }
+            let forbidden_inside = forbidden_spans
+                .iter()
+                .any(|&(keyword_start, keyword_end)| keyword_start < end && keyword_end > start);
+            if forbidden_inside
+                || self.has_span_near(
+                    content,
+                    &forbidden_spans,
+                    start,
+                    end,
+                    ContextDirection::Either,
+                )
+            {
+                continue;
+            }
+            matches.push(SecretMatch::from_match(self.secret_type.clone(), value));
+        }
+
+        matches
+    }
+}
+
+/// Context rules run as detectors when passed alongside the built-in ones
+///
+/// They always run, so they share a single name rather than one per rule.
+impl Detector for ContextRule {
+    fn name(&self) -> &str {
+        "context_rule"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        self.find_matches(content)
+    }
+
+    fn confidence(&self) -> Confidence {
+        self.secret_type.confidence()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_window() {
+        let content = "before KEY after";
+        assert_eq!(context_window(content, 7, 10, 3), "re KEY af");
+        assert_eq!(context_window(content, 7, 10, 100), content);
+        assert_eq!(context_window(content, 0, 0, 0), "");
+    }
+
+    #[test]
+    fn test_context_window_respects_char_boundaries() {
+        let content = "ééKEYüü";
+        let start = content.find("KEY").unwrap();
+        // One byte of radius lands inside the two-byte characters and is widened
+        assert_eq!(context_window(content, start, start + 3, 1), "éKEYü");
+    }
+    #[test]
+    fn test_context_rule_window_boundary_in_chars() {
+        let rule = ContextRule::new(
+            SecretType::Custom("Test Key".to_string()),
+            r"\b[0-9a-f]{8}\b",
+            &["vendor"],
+        )
+        .unwrap()
+        .window(ContextWindow::Chars(5));
+        // Exactly 5 characters between keyword and value
+        assert_eq!(rule.find_matches("vendor=:::'deadbeef").len(), 1);
+        // 6 characters is outside the window
+        assert!(rule.find_matches("vendor=::::'deadbeef").is_empty());
+    }
+
+    #[test]
+    fn test_context_rule_window_boundary_in_lines() {
+        let rule = ContextRule::new(
+            SecretType::Custom("Test Key".to_string()),
+            r"\b[0-9a-f]{8}\b",
+            &["vendor"],
+        )
+        .unwrap()
+        .window(ContextWindow::Lines(1));
+        assert_eq!(rule.find_matches("[vendor]\nkey = deadbeef").len(), 1);
+        assert!(rule.find_matches("[vendor]\n\nkey = deadbeef").is_empty());
+    }
+
+    #[test]
+    fn test_context_rule_direction() {
+        let before = ContextRule::new(
+            SecretType::Custom("Test Key".to_string()),
+            r"\b[0-9a-f]{8}\b",
+            &["vendor"],
+        )
+        .unwrap()
+        .direction(ContextDirection::Before);
+        let after = ContextRule::new(
+            SecretType::Custom("Test Key".to_string()),
+            r"\b[0-9a-f]{8}\b",
+            &["vendor"],
+        )
+        .unwrap()
+        .direction(ContextDirection::After);
+        assert_eq!(before.find_matches("VENDOR_KEY=deadbeef").len(), 1);
+        assert!(after.find_matches("VENDOR_KEY=deadbeef").is_empty());
+        assert!(before.find_matches("deadbeef # vendor key").is_empty());
+        assert_eq!(after.find_matches("deadbeef # vendor key").len(), 1);
+    }
+
+    #[test]
+    fn test_context_rule_forbidden_keyword() {
+        let rule = ContextRule::new(
+            SecretType::Custom("Test Key".to_string()),
+            r"\b([0-9a-f]{8})\b",
+            &["vendor"],
+        )
+        .unwrap()
+        .forbidden(&["example"]);
+        assert_eq!(
+            rule.find_matches("vendor_key: deadbeef")[0].value,
+            "deadbeef"
+        );
+        assert!(rule
+            .find_matches("vendor_key: deadbeef # example value")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_context_rule_without_keyword() {
+        let rule = ContextRule::new(
+            SecretType::Custom("Test Key".to_string()),
+            r"\b[0-9a-f]{8}\b",
+            &["vendor"],
+        )
+        .unwrap();
+        assert!(rule.find_matches("key = deadbeef").is_empty());
+    }
+}
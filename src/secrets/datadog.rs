@@ -0,0 +1,99 @@
+use once_cell::sync::Lazy;
+
+use super::context::{ContextRule, PLACEHOLDER_KEYWORDS};
+use super::detector::Detector;
+use super::secret_match::SecretMatch;
+use super::secret_type::SecretType;
+
+/// Context rules for Datadog keys
+/// API keys are 32 and application keys 40 lowercase hex characters with no prefix
+static DATADOG_KEY_RULES: Lazy<Vec<ContextRule>> = Lazy::new(|| {
+    vec![
+        ContextRule::new(
+            SecretType::DatadogApiKey,
+            r"\b([0-9a-f]{32})\b",
+            &["datadog", "dd_api_key", "dd-api-key"],
+        )
+        .expect("Invalid regex pattern")
+        .forbidden(PLACEHOLDER_KEYWORDS),
+        ContextRule::new(
+            SecretType::DatadogApplicationKey,
+            r"\b([0-9a-f]{40})\b",
+            &[
+                "dd_app_key",
+                "dd_application_key",
+                "dd-application-key",
+                "datadog_app_key",
+                "datadog_application_key",
+            ],
+        )
+        .expect("Invalid regex pattern")
+        .forbidden(PLACEHOLDER_KEYWORDS),
+    ]
+});
+
+/// Detects Datadog API and application keys next to a Datadog keyword
+///
+/// # Arguments
+/// * `content` - The string to check for Datadog keys
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_datadog_keys(content: &str) -> Vec<SecretMatch> {
+    DATADOG_KEY_RULES
+        .iter()
+        .flat_map(|rule| rule.find_matches(content))
+        .collect()
+}
+
+/// Detector registered as `"datadog"`; see [`detect_datadog_keys`]
+pub struct DatadogDetector;
+
+impl Detector for DatadogDetector {
+    fn name(&self) -> &str {
+        "datadog"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_datadog_keys(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(
+            r"(?i)datadog|dd_api_key|dd-api-key|dd_app_key|dd_application_key|dd-application-key"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_datadog_agent_env() {
+        let env = "DD_API_KEY=3f9a1c7e5b2d8f4a6c0e9b1d7f3a5c2e\nDD_APP_KEY=0a1b2c3d4e5f60718293a4b5c6d7e8f901234567\n";
+        let result = detect_datadog_keys(env);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].secret_type, "Datadog API Key");
+        assert_eq!(result[0].value, "3f9a1c7e5b2d8f4a6c0e9b1d7f3a5c2e");
+        assert_eq!(result[1].secret_type, "Datadog Application Key");
+        assert_eq!(result[1].value, "0a1b2c3d4e5f60718293a4b5c6d7e8f901234567");
+    }
+
+    #[test]
+    fn test_datadog_helm_values() {
+        let values = "datadog:\n  apiKey: 3f9a1c7e5b2d8f4a6c0e9b1d7f3a5c2e\n";
+        let result = detect_datadog_keys(values);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Datadog API Key");
+    }
+
+    #[test]
+    fn test_hex_without_datadog_context() {
+        assert!(detect_datadog_keys("sha1: 0a1b2c3d4e5f60718293a4b5c6d7e8f901234567").is_empty());
+        assert!(
+            detect_datadog_keys("DD_API_KEY=00000000000000000000000000000000 # sample").is_empty()
+        );
+    }
+}
@@ -1,10 +1,24 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use super::detector::Detector;
+use super::prescreen::{Anchor, Reach};
+use super::secret_match::{Confidence, SecretMatch};
+use super::secret_type::SecretType;
+use super::token_rule::{RuleOverride, TokenRule, WidenedDetector};
+
+/// Override-safe shape of DigitalOcean personal, OAuth and refresh tokens
+const DIGITALOCEAN_KEY_RULE: TokenRule = TokenRule {
+    prefix: r"\b(?:dop|doo|dor)_v1_",
+    charset: "a-f0-9",
+    min_len: 64,
+    max_len: 64,
+    suffix: r"\b",
+};
+
 /// Regex pattern for DigitalOcean API key detection
-static DIGITALOCEAN_KEY_PATTERN: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"\b((?:dop|doo|dor)_v1_[a-f0-9]{64})\b").expect("Invalid regex pattern")
-});
+static DIGITALOCEAN_KEY_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(&DIGITALOCEAN_KEY_RULE.pattern()).expect("Invalid regex pattern"));
 
 /// Detects all DigitalOcean API keys in a string
 ///
@@ -12,20 +26,57 @@ static DIGITALOCEAN_KEY_PATTERN: Lazy<Regex> = Lazy::new(|| {
 /// * `secret` - The string to check for DigitalOcean key patterns
 ///
 /// # Returns
-/// * `Vec<(String, String)>` - List of all (secret_type, value) pairs found
-pub fn detect_digitalocean_keys(secret: &str) -> Vec<(String, String)> {
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_digitalocean_keys(secret: &str) -> Vec<SecretMatch> {
     let mut keys = Vec::new();
 
     for key_match in DIGITALOCEAN_KEY_PATTERN.find_iter(secret) {
-        keys.push((
-            "DigitalOcean API Key".to_string(),
-            key_match.as_str().to_string(),
+        keys.push(SecretMatch::from_match(
+            SecretType::DigitalOceanApiKey,
+            key_match,
         ));
     }
 
     keys
 }
 
+/// Detector registered as `"digitalocean"`; see [`detect_digitalocean_keys`]
+pub struct DigitalOceanDetector;
+
+impl Detector for DigitalOceanDetector {
+    fn name(&self) -> &str {
+        "digitalocean"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_digitalocean_keys(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(DIGITALOCEAN_KEY_RULE.prefix.to_string())
+    }
+
+    fn anchor(&self) -> Option<Anchor> {
+        Some(Anchor::new(
+            &["dop_v1_", "doo_v1_", "dor_v1_"],
+            Reach::Bytes(0),
+            Reach::Bytes("dop_v1_".len() + DIGITALOCEAN_KEY_RULE.max_len),
+        ))
+    }
+
+    fn with_override(&self, rule: &RuleOverride) -> Result<Box<dyn Detector>, String> {
+        Ok(Box::new(WidenedDetector::new(
+            "digitalocean",
+            SecretType::DigitalOceanApiKey,
+            DIGITALOCEAN_KEY_RULE.widen(rule)?,
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,9 +90,9 @@ mod tests {
         let key = build_key("dop");
         let result = detect_digitalocean_keys(&key);
         assert!(!result.is_empty());
-        let (secret_type, value) = result.first().unwrap();
+        let (secret_type, value) = result[0].clone().into_pair();
         assert_eq!(secret_type, "DigitalOcean API Key");
-        assert_eq!(value, &key);
+        assert_eq!(value, key);
     }
 
     #[test]
@@ -50,7 +101,7 @@ mod tests {
         let code = format!("DO_KEY = '{key}'");
         let result = detect_digitalocean_keys(&code);
         assert!(!result.is_empty());
-        assert_eq!(result.first().unwrap().1, key);
+        assert_eq!(result.first().unwrap().value, key);
     }
 
     #[test]
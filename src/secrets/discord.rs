@@ -1,6 +1,10 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use super::detector::Detector;
+use super::secret_match::SecretMatch;
+use super::secret_type::SecretType;
+
 /// Regex pattern for Discord bot token detection
 /// Format: [M|N|O] + 23-25 chars + '.' + 6 chars + '.' + 27 chars
 static DISCORD_TOKEN_PATTERN: Lazy<Regex> = Lazy::new(|| {
@@ -8,38 +12,76 @@ static DISCORD_TOKEN_PATTERN: Lazy<Regex> = Lazy::new(|| {
         .expect("Invalid regex pattern")
 });
 
-/// Detects all Discord bot tokens in a string
+/// Regex pattern for Discord webhook URLs
+/// Format: discord.com/api/webhooks/<snowflake id>/<token>, also on the ptb/canary and
+/// legacy discordapp.com hosts
+static DISCORD_WEBHOOK_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"https://(?:(?:ptb|canary)\.)?discord(?:app)?\.com/api/webhooks/\d{17,20}/[a-zA-Z\d_-]{60,68}",
+    )
+    .expect("Invalid regex pattern")
+});
+
+/// Detects all Discord bot tokens and webhook URLs in a string
+///
+/// Webhook URLs are "Discord Webhook URL": they let anyone post to one channel, so they
+/// start at Medium confidence rather than High.
 ///
 /// # Arguments
-/// * `secret` - The string to check for Discord bot token patterns
+/// * `secret` - The string to check for Discord bot token and webhook patterns
 ///
 /// # Returns
-/// * `Vec<(String, String)>` - List of all (secret_type, value) pairs found
-pub fn detect_discord_tokens(secret: &str) -> Vec<(String, String)> {
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_discord_tokens(secret: &str) -> Vec<SecretMatch> {
     let mut tokens = Vec::new();
 
     for token_match in DISCORD_TOKEN_PATTERN.find_iter(secret) {
-        tokens.push((
-            "Discord Bot Token".to_string(),
-            token_match.as_str().to_string(),
+        tokens.push(SecretMatch::from_match(
+            SecretType::DiscordBotToken,
+            token_match,
+        ));
+    }
+
+    for webhook_match in DISCORD_WEBHOOK_PATTERN.find_iter(secret) {
+        tokens.push(SecretMatch::from_match(
+            SecretType::DiscordWebhookUrl,
+            webhook_match,
         ));
     }
 
     tokens
 }
 
+/// Detector registered as `"discord"`; see [`detect_discord_tokens`]
+pub struct DiscordDetector;
+
+impl Detector for DiscordDetector {
+    fn name(&self) -> &str {
+        "discord"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_discord_tokens(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"\.[a-zA-Z\d_-]{6}\.[a-zA-Z\d_-]{27}|discord(?:app)?\.com/api/webhooks/".to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::secrets::secret_match::Confidence;
 
     #[test]
     fn test_valid_discord_token() {
         let token = format!("M{}.{}.{}", "a".repeat(23), "b".repeat(6), "c".repeat(27));
         let result = detect_discord_tokens(&token);
         assert!(!result.is_empty());
-        let (secret_type, value) = result.first().unwrap();
+        let (secret_type, value) = result[0].clone().into_pair();
         assert_eq!(secret_type, "Discord Bot Token");
-        assert_eq!(value, &token);
+        assert_eq!(value, token);
     }
 
     #[test]
@@ -48,7 +90,7 @@ mod tests {
         let code = format!("DISCORD_TOKEN = '{token}'");
         let result = detect_discord_tokens(&code);
         assert!(!result.is_empty());
-        assert_eq!(result.first().unwrap().1, token);
+        assert_eq!(result.first().unwrap().value, token);
     }
 
     #[test]
@@ -58,8 +100,8 @@ mod tests {
         let content = format!("{token1} and {token2}");
         let results = detect_discord_tokens(&content);
         assert_eq!(results.len(), 2);
-        assert_eq!(results[0].0, "Discord Bot Token");
-        assert_eq!(results[1].0, "Discord Bot Token");
+        assert_eq!(results[0].secret_type, "Discord Bot Token");
+        assert_eq!(results[1].secret_type, "Discord Bot Token");
     }
 
     #[test]
@@ -73,4 +115,17 @@ mod tests {
         let token = format!("M{}.{}.{}", "a".repeat(23), "b".repeat(5), "c".repeat(27));
         assert!(detect_discord_tokens(&token).is_empty());
     }
+
+    #[test]
+    fn test_discord_webhook_url() {
+        let webhook = format!(
+            "https://discord.com/api/webhooks/1123456789012345678/{}",
+            "Xy9_k2-LmNpQrStUvWxYz0123456789AbCdEfGhIjKlMnOpQrStUvWxYz0123456789"
+        );
+        let result = detect_discord_tokens(&format!("DISCORD_WEBHOOK={webhook}"));
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Discord Webhook URL");
+        assert_eq!(result[0].confidence, Confidence::Medium);
+        assert_eq!(result[0].value, webhook);
+    }
 }
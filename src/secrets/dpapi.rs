@@ -0,0 +1,174 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::detector::Detector;
+use super::secret_match::{Confidence, SecretMatch};
+use super::secret_type::SecretType;
+
+/// Regex pattern for base64-encoded DPAPI blobs
+/// `AQAAANCMnd8BFdERjHoAwE` is the base64 of the blob header: version 1 followed by the
+/// DPAPI provider GUID df9d8cd0-1501-11d1-8c7a-00c04fc297eb, so it starts every blob
+/// encoded on its own
+const DPAPI_BLOB: &str = r"AQAAANCMnd8BFdERjHoAwE[A-Za-z0-9+/]{20,}={0,2}";
+
+static DPAPI_BLOB_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(DPAPI_BLOB).expect("Invalid regex pattern"));
+
+/// The same pattern over raw bytes, for disk images and binary files
+static DPAPI_BLOB_BYTES_PATTERN: Lazy<regex::bytes::Regex> = Lazy::new(|| {
+    regex::bytes::Regex::new(&format!("(?-u){DPAPI_BLOB}")).expect("Invalid regex pattern")
+});
+
+/// Regex pattern for the `encrypted_key` in a Chromium `Local State` file
+/// The key is the base64 of `DPAPI` followed by a DPAPI blob, hence the `RFBBUEk` prefix
+/// Pattern captures: (1) the base64 key
+static LOCAL_STATE_KEY_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#""encrypted_key"\s*:\s*"(RFBBUEk[A-Za-z0-9+/]{20,}={0,2})""#)
+        .expect("Invalid regex pattern")
+});
+
+/// Detects DPAPI-protected blobs and Chromium Local State encryption keys
+///
+/// Both are encrypted to a Windows user or machine, so they are reported with Low
+/// confidence: the plaintext isn't exposed, but a disk image holding them is worth
+/// a closer look. Nothing is decrypted.
+///
+/// # Arguments
+/// * `content` - The string to check
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_dpapi_blobs(content: &str) -> Vec<SecretMatch> {
+    let mut blobs = Vec::new();
+
+    if content.contains("AQAAANCMnd8BFdERjHoAwE") {
+        for blob in DPAPI_BLOB_PATTERN.find_iter(content) {
+            blobs.push(SecretMatch::from_match(
+                SecretType::DpapiProtectedBlob,
+                blob,
+            ));
+        }
+    }
+
+    if content.contains("encrypted_key") {
+        for captures in LOCAL_STATE_KEY_PATTERN.captures_iter(content) {
+            if let Some(key) = captures.get(1) {
+                blobs.push(SecretMatch::from_match(
+                    SecretType::ChromeLocalStateEncryptedKey,
+                    key,
+                ));
+            }
+        }
+    }
+
+    blobs
+}
+
+/// Detects base64-encoded DPAPI blobs in binary data
+///
+/// Binary files, like a `Login Data` database or a registry hive, can't be scanned as
+/// text. This finds the same blobs as [`detect_dpapi_blobs`] wherever they sit among
+/// other bytes.
+///
+/// # Arguments
+/// * `bytes` - The data to check
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into `bytes`
+pub fn detect_dpapi_blobs_in_bytes(bytes: &[u8]) -> Vec<SecretMatch> {
+    DPAPI_BLOB_BYTES_PATTERN
+        .find_iter(bytes)
+        .map(|blob| {
+            // The pattern only matches ASCII, so the blob is valid UTF-8
+            let value = String::from_utf8_lossy(blob.as_bytes());
+            SecretMatch::new(
+                SecretType::DpapiProtectedBlob,
+                value,
+                blob.start(),
+                blob.end(),
+            )
+        })
+        .collect()
+}
+
+/// Detector registered as `"dpapi"`; see [`detect_dpapi_blobs`]
+pub struct DpapiDetector;
+
+impl Detector for DpapiDetector {
+    fn name(&self) -> &str {
+        "dpapi"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::Low
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_dpapi_blobs(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"AQAAANCMnd8BFdERjHoAwE|encrypted_key".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secrets::secret_match::Confidence;
+
+    const BLOB: &str =
+        "AQAAANCMnd8BFdERjHoAwE/Cl+sBAgMEBQYHCAkKCwwNDg8QERITFBUWFxgZGhscHR4fICEiIyQlJico";
+    const LOCAL_STATE_KEY: &str =
+        "RFBBUEkBAAAA0Iyd3wEV0RGMegDAT8KX6wECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJyg=";
+
+    #[test]
+    fn test_local_state_encrypted_key() {
+        let local_state = format!(
+            r#"{{"browser":{{"enabled_labs_experiments":[]}},"os_crypt":{{"audit_enabled":true,"encrypted_key":"{LOCAL_STATE_KEY}"}},"profile":{{"info_cache":{{}}}}}}"#
+        );
+        let found = detect_dpapi_blobs(&local_state);
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            found[0].secret_type.to_string(),
+            "Chrome Local State Encrypted Key"
+        );
+        assert_eq!(found[0].value, LOCAL_STATE_KEY);
+        assert_eq!(&local_state[found[0].start..found[0].end], LOCAL_STATE_KEY);
+        assert_eq!(found[0].confidence, Confidence::Low);
+
+        // Only DPAPI-wrapped keys are reported
+        assert!(
+            detect_dpapi_blobs(r#"{"encrypted_key": "djEwAAAAaGVsbG8gd29ybGQhIQ=="}"#).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_dpapi_blob_in_text() {
+        let content = format!(
+            "<connectionStrings>\n  <add name=\"Billing\" protected=\"{BLOB}\" />\n</connectionStrings>\n"
+        );
+        let found = detect_dpapi_blobs(&content);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].secret_type.to_string(), "DPAPI Protected Blob");
+        assert_eq!(found[0].value, BLOB);
+        assert_eq!(found[0].confidence, Confidence::Low);
+
+        // The header alone, without a payload, is not a blob
+        assert!(detect_dpapi_blobs("marker = AQAAANCMnd8BFdERjHoAwE").is_empty());
+    }
+
+    #[test]
+    fn test_dpapi_blob_in_binary_data() {
+        let mut bytes = vec![0x53, 0x51, 0x4c, 0x69, 0x74, 0x65, 0x00, 0xff, 0xfe, 0x80];
+        let start = bytes.len();
+        bytes.extend_from_slice(BLOB.as_bytes());
+        bytes.extend_from_slice(&[0x00, 0xc3, 0x28, 0x01]);
+        assert!(std::str::from_utf8(&bytes).is_err());
+
+        let found = detect_dpapi_blobs_in_bytes(&bytes);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].value, BLOB);
+        assert_eq!((found[0].start, found[0].end), (start, start + BLOB.len()));
+    }
+}
@@ -0,0 +1,170 @@
+//! Signals that raise or lower a match's confidence, combined in one place
+//!
+//! Checks that run after a detector, such as the placeholder filter or a custom rule's
+//! checksum, don't overwrite [`SecretMatch::confidence`] themselves. Each attaches an
+//! [`Evidence`] with [`SecretMatch::add_evidence`], and [`score`] computes the
+//! confidence from the detector's and all the evidence at once, so the result doesn't
+//! depend on which check ran first.
+//!
+//! [`SecretMatch::confidence`]: super::secret_match::SecretMatch::confidence
+//! [`SecretMatch::add_evidence`]: super::secret_match::SecretMatch::add_evidence
+
+use super::secret_match::Confidence;
+
+/// The check that produced a piece of evidence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Signal {
+    /// The value or its surroundings look like an example, see
+    /// [`crate::PlaceholderFilter`]
+    Placeholder,
+    /// The value's checksum held or failed, such as a GitHub token's CRC-32 or a custom
+    /// rule's, see [`crate::OrgTokenSpec::checksum`]
+    Checksum,
+    /// The match was found somewhere its kind of secret should never be written, such
+    /// as a GitHub installation token in a file
+    Context,
+    /// The credential had expired, see [`crate::ScannerBuilder::suppress_expired`]
+    Expired,
+    /// The value reads as dictionary words, see
+    /// [`crate::ScannerBuilder::demote_dictionary_words`]
+    DictionaryWords,
+    /// The file is vendored or minified, see [`crate::VendoredHandling`]
+    Vendored,
+    /// The file is a test fixture, see [`crate::ScannerBuilder::demote_test_fixtures`];
+    /// raises matches that look real and lowers the rest
+    TestFixture,
+}
+
+/// Whether evidence makes a match more or less likely to be a real secret
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Direction {
+    Raise,
+    Lower,
+}
+
+/// One signal's say in a match's confidence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Evidence {
+    pub signal: Signal,
+    /// How many confidence levels the signal moves the match by; 2 takes any match to
+    /// [`Confidence::Low`] or [`Confidence::High`] on its own
+    pub weight: u8,
+    pub direction: Direction,
+}
+
+impl Evidence {
+    /// Creates evidence that raises confidence by `weight` levels
+    pub fn raise(signal: Signal, weight: u8) -> Self {
+        Evidence {
+            signal,
+            weight,
+            direction: Direction::Raise,
+        }
+    }
+
+    /// Creates evidence that lowers confidence by `weight` levels
+    pub fn lower(signal: Signal, weight: u8) -> Self {
+        Evidence {
+            signal,
+            weight,
+            direction: Direction::Lower,
+        }
+    }
+}
+
+/// Weight of evidence that decides a match's confidence on its own
+pub(crate) const DECISIVE: u8 = 2;
+
+/// Computes a match's confidence from its detector's and the evidence attached to it
+///
+/// Levels are numbered Low = 0, Medium = 1 and High = 2. Each piece of evidence adds
+/// its weight to the base level, or subtracts it, and only the total is clamped to
+/// Low..=High. Addition doesn't care about order, so neither does the result: a match
+/// raised by 2 and lowered by 1 ends one level up, whichever came first.
+///
+/// # Arguments
+/// * `base` - The confidence the detector reported
+/// * `evidence` - Every piece of evidence attached to the match, in any order
+///
+/// # Returns
+/// * `Confidence` - The match's confidence
+pub fn score(base: Confidence, evidence: &[Evidence]) -> Confidence {
+    let level = |confidence| match confidence {
+        Confidence::Low => 0,
+        Confidence::Medium => 1,
+        Confidence::High => 2,
+    };
+    let total = evidence
+        .iter()
+        .fold(level(base), |total, evidence| match evidence.direction {
+            Direction::Raise => total + i32::from(evidence.weight),
+            Direction::Lower => total - i32::from(evidence.weight),
+        });
+    match total {
+        i32::MIN..=0 => Confidence::Low,
+        1 => Confidence::Medium,
+        _ => Confidence::High,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secrets::secret_match::SecretMatch;
+    use crate::secrets::secret_type::SecretType;
+
+    #[test]
+    fn test_score_is_order_independent() {
+        let evidence = [
+            Evidence::raise(Signal::Context, 2),
+            Evidence::lower(Signal::Placeholder, 1),
+            Evidence::lower(Signal::DictionaryWords, 1),
+            Evidence::raise(Signal::Checksum, 1),
+        ];
+        let mut scores = Vec::new();
+        // Every rotation, forwards and backwards
+        for start in 0..evidence.len() {
+            for reversed in [false, true] {
+                let mut ordered = evidence.to_vec();
+                ordered.rotate_left(start);
+                if reversed {
+                    ordered.reverse();
+                }
+                let mut found = SecretMatch::new(SecretType::GenericSecret, "hunter2pass", 0, 11);
+                for evidence in ordered {
+                    found.add_evidence(evidence);
+                }
+                scores.push(found.confidence);
+            }
+        }
+        assert!(scores.iter().all(|&score| score == Confidence::High));
+
+        // Clamping at every step would have ended at Medium
+        assert_eq!(
+            score(
+                Confidence::Medium,
+                &[
+                    Evidence::raise(Signal::Context, 2),
+                    Evidence::lower(Signal::Placeholder, 1)
+                ]
+            ),
+            Confidence::High
+        );
+        assert_eq!(score(Confidence::High, &[]), Confidence::High);
+        assert_eq!(
+            score(Confidence::High, &[Evidence::lower(Signal::Expired, 2)]),
+            Confidence::Low
+        );
+    }
+}
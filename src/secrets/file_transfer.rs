@@ -0,0 +1,214 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::detector::Detector;
+use super::secret_match::SecretMatch;
+use super::secret_type::SecretType;
+
+/// Regex pattern for lftp's comma-separated `-u user,password` login form
+/// Pattern captures: (1) the password
+static LFTP_USER_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\blftp\b[^\n]*?\s-u\s+['"]?[^,\s'"]+,([^\s'"]+)"#).expect("Invalid regex pattern")
+});
+
+/// Regex pattern for curl/wget `-u`/`--user` flags in `user:password` form
+/// Pattern captures: (1) the password, possibly empty
+static CURL_USER_FLAG_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\b(?:curl|wget)\b[^\n]*?\s(?:-u\s*|--user[= ])['"]?[^:\s'"]+:([^\s'"]*)"#)
+        .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for wget's separate password flags
+/// Pattern captures: (1) the password
+static WGET_PASSWORD_FLAG_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\bwget\b[^\n]*?\s--(?:ftp-|http-)?password[= ]['"]?([^\s'"]+)"#)
+        .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for the RSYNC_PASSWORD environment variable
+/// Pattern captures: (1) the password
+static RSYNC_PASSWORD_ENV_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\bRSYNC_PASSWORD=['"]?([^\s'"]+)"#).expect("Invalid regex pattern"));
+
+/// Regex pattern for a plaintext value echoed into a file
+/// Pattern captures: (1) the value, (2) the file path
+static ECHO_TO_FILE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\becho\s+(?:-n\s+)?['"]?([^\s'">]+)['"]?\s*>>?\s*(\S+)"#)
+        .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for rsync's `--password-file` flag
+/// Pattern captures: (1) the file path
+static RSYNC_PASSWORD_FILE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\brsync\b[^\n]*?\s--password-file[= ](\S+)").expect("Invalid regex pattern")
+});
+
+/// Regex pattern for WinSCP ini `Password=` entries
+/// WinSCP stores session passwords XOR-obfuscated as hex, which is trivially reversible
+/// Pattern captures: (1) the obfuscated password
+static WINSCP_PASSWORD_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^Password=([0-9A-Fa-f]{10,})\s*$").expect("Invalid regex pattern")
+});
+
+/// Checks whether a value is a shell variable or command substitution
+fn is_variable_reference(value: &str) -> bool {
+    value.starts_with('$') || value.starts_with('`') || value.starts_with('%')
+}
+
+/// Detects passwords embedded in file transfer commands and configs
+///
+/// Covers:
+/// - lftp `-u user,password` logins
+/// - curl/wget `-u user:password` and `--user user:password` flags, plus wget's
+///   `--password`/`--ftp-password`/`--http-password` flags
+/// - `RSYNC_PASSWORD` assignments, and plaintext echoed into a file that is later
+///   passed to `rsync --password-file`
+/// - WinSCP ini `Password=` entries, which are obfuscated but reversible
+///
+/// Empty passwords and variable references are skipped.
+///
+/// # Arguments
+/// * `content` - The string to check for file transfer passwords
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_file_transfer_passwords(content: &str) -> Vec<SecretMatch> {
+    let mut secrets = Vec::new();
+
+    for pattern in [
+        &*LFTP_USER_PATTERN,
+        &*CURL_USER_FLAG_PATTERN,
+        &*WGET_PASSWORD_FLAG_PATTERN,
+        &*RSYNC_PASSWORD_ENV_PATTERN,
+    ] {
+        for captures in pattern.captures_iter(content) {
+            if let Some(password) = captures.get(1) {
+                if !password.is_empty() && !is_variable_reference(password.as_str()) {
+                    secrets.push(SecretMatch::from_match(
+                        SecretType::FileTransferPassword,
+                        password,
+                    ));
+                }
+            }
+        }
+    }
+
+    let password_files: Vec<&str> = RSYNC_PASSWORD_FILE_PATTERN
+        .captures_iter(content)
+        .filter_map(|captures| captures.get(1))
+        .map(|path| path.as_str().trim_matches(|c| c == '"' || c == '\''))
+        .collect();
+    for captures in ECHO_TO_FILE_PATTERN.captures_iter(content) {
+        if let (Some(value), Some(path)) = (captures.get(1), captures.get(2)) {
+            let path = path.as_str().trim_matches(|c| c == '"' || c == '\'');
+            if password_files.contains(&path) && !is_variable_reference(value.as_str()) {
+                secrets.push(SecretMatch::from_match(
+                    SecretType::FileTransferPassword,
+                    value,
+                ));
+            }
+        }
+    }
+
+    for captures in WINSCP_PASSWORD_PATTERN.captures_iter(content) {
+        if let Some(password) = captures.get(1) {
+            secrets.push(SecretMatch::from_match(
+                SecretType::WinScpPassword,
+                password,
+            ));
+        }
+    }
+
+    secrets
+}
+
+/// Detector registered as `"file_transfer"`; see [`detect_file_transfer_passwords`]
+pub struct FileTransferDetector;
+
+impl Detector for FileTransferDetector {
+    fn name(&self) -> &str {
+        "file_transfer"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_file_transfer_passwords(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"lftp|curl|wget|rsync|RSYNC_PASSWORD=|(?m)^Password=".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lftp_mirror_script() {
+        let script = r#"#!/bin/bash
+lftp -u deploy,Mirr0r!Pass sftp://files.example.com -e "mirror -R ./dist /var/www; quit"
+lftp -u deploy,"$FTP_PASSWORD" sftp://files.example.com
+"#;
+        let result = detect_file_transfer_passwords(script);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "File Transfer Password");
+        assert_eq!(result[0].value, "Mirr0r!Pass");
+    }
+
+    #[test]
+    fn test_curl_user_flag() {
+        let cmd = "curl -T backup.tar.gz -u backup:Upl0adS3cret ftp://ftp.example.com/backups/";
+        let result = detect_file_transfer_passwords(cmd);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].value, "Upl0adS3cret");
+
+        let cmd = "curl --user 'backup:Upl0adS3cret' ftps://ftp.example.com/";
+        assert_eq!(detect_file_transfer_passwords(cmd)[0].value, "Upl0adS3cret");
+    }
+
+    #[test]
+    fn test_curl_user_flag_with_empty_password() {
+        // curl prompts for the password when it is left empty
+        assert!(
+            detect_file_transfer_passwords("curl -u backup: ftp://ftp.example.com/").is_empty()
+        );
+        assert!(detect_file_transfer_passwords("curl -u \"$USER:$PASS\" ftp://h/").is_empty());
+    }
+
+    #[test]
+    fn test_wget_password_flag() {
+        let cmd = "wget --ftp-user=mirror --ftp-password=W9etPass ftp://ftp.example.com/pub/file";
+        let result = detect_file_transfer_passwords(cmd);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].value, "W9etPass");
+    }
+
+    #[test]
+    fn test_rsync_password_file_with_echoed_password() {
+        let script = "echo 'RsyncPa55' > /tmp/rsync.pass\nchmod 600 /tmp/rsync.pass\nrsync -av --password-file=/tmp/rsync.pass ./data backup@nas::share\n";
+        let result = detect_file_transfer_passwords(script);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].value, "RsyncPa55");
+
+        // Echoing into an unrelated file is not reported
+        assert!(detect_file_transfer_passwords("echo hello > /tmp/out.txt").is_empty());
+    }
+
+    #[test]
+    fn test_rsync_password_env() {
+        let cmd = "RSYNC_PASSWORD=Env5ecret rsync -av ./data backup@nas::share";
+        assert_eq!(detect_file_transfer_passwords(cmd)[0].value, "Env5ecret");
+    }
+
+    #[test]
+    fn test_winscp_ini_password() {
+        let ini = "[Sessions\\deploy@files.example.com]\nHostName=files.example.com\nUserName=deploy\nPassword=A35C435B9556B1237C2B3E6E64726D72656D6F\n";
+        let result = detect_file_transfer_passwords(ini);
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].secret_type,
+            "WinSCP Password (Reversible Encryption)"
+        );
+        assert_eq!(result[0].value, "A35C435B9556B1237C2B3E6E64726D72656D6F");
+    }
+}
@@ -0,0 +1,178 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::detector::Detector;
+use super::secret_match::SecretMatch;
+use super::secret_type::SecretType;
+
+/// Regex pattern for Google OAuth 2.0 refresh tokens
+/// Format: 1//0 followed by 50+ base64url characters
+static GOOGLE_REFRESH_TOKEN_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b1//0[A-Za-z0-9_-]{50,}").expect("Invalid regex pattern"));
+
+/// Regex pattern for short-lived Google OAuth 2.0 access tokens
+/// Format: ya29. followed by base64url characters
+/// These expire after an hour, so they are labeled separately from refresh tokens
+static GOOGLE_ACCESS_TOKEN_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bya29\.[0-9A-Za-z_-]{20,}").expect("Invalid regex pattern"));
+
+/// Seconds a Google OAuth access token stays valid after it is issued
+pub(crate) const ACCESS_TOKEN_LIFETIME: u64 = 3600;
+
+/// Regex pattern for the type marker of a Workload Identity Federation credential config
+static EXTERNAL_ACCOUNT_TYPE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#""type"\s*:\s*"external_account""#).expect("Invalid regex pattern"));
+
+/// Regex pattern for the service account impersonation URL of an external_account config
+static IMPERSONATION_URL_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#""service_account_impersonation_url"\s*:\s*"https://iamcredentials\.googleapis\.com/"#,
+    )
+    .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for the audience of an external_account config
+/// Pattern captures: (1) the workload identity pool provider audience
+static EXTERNAL_ACCOUNT_AUDIENCE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#""audience"\s*:\s*"(//iam\.googleapis\.com/[^"]+)""#)
+        .expect("Invalid regex pattern")
+});
+
+/// Detects Google OAuth tokens and Workload Identity Federation credential configs
+///
+/// Covers:
+/// - OAuth refresh tokens (1//0...)
+/// - OAuth access tokens (ya29....), labeled separately since they are short-lived
+/// - Committed `"type": "external_account"` credential configs that impersonate a
+///   service account; the workload identity pool audience is reported as the value
+///
+/// # Arguments
+/// * `content` - The string to check for Google credential patterns
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_google_credentials(content: &str) -> Vec<SecretMatch> {
+    let mut secrets = Vec::new();
+
+    for token_match in GOOGLE_REFRESH_TOKEN_PATTERN.find_iter(content) {
+        secrets.push(SecretMatch::from_match(
+            SecretType::GoogleOAuthRefreshToken,
+            token_match,
+        ));
+    }
+
+    for token_match in GOOGLE_ACCESS_TOKEN_PATTERN.find_iter(content) {
+        secrets.push(SecretMatch::from_match(
+            SecretType::GoogleOAuthAccessToken,
+            token_match,
+        ));
+    }
+
+    if EXTERNAL_ACCOUNT_TYPE_PATTERN.is_match(content)
+        && IMPERSONATION_URL_PATTERN.is_match(content)
+    {
+        for captures in EXTERNAL_ACCOUNT_AUDIENCE_PATTERN.captures_iter(content) {
+            if let Some(audience) = captures.get(1) {
+                secrets.push(SecretMatch::from_match(
+                    SecretType::GcpWorkloadIdentityFederationConfig,
+                    audience,
+                ));
+            }
+        }
+    }
+
+    secrets
+}
+
+/// Detector registered as `"google"`; see [`detect_google_credentials`]
+pub struct GoogleDetector;
+
+impl Detector for GoogleDetector {
+    fn name(&self) -> &str {
+        "google"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_google_credentials(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r#"1//0|ya29\.|"external_account""#.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_access_token_in_curl() {
+        let token =
+            "ya29.a0AfH6SMBx3kQ9vZ7LmN2pR4tY8wE1uI5oP0aS6dF3gH9jK2lZ7xC4vB1nM8qW5eR0tY3uI6oP";
+        let curl = format!(
+            "curl -H \"Authorization: Bearer {token}\" https://storage.googleapis.com/storage/v1/b"
+        );
+        let result = detect_google_credentials(&curl);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Google OAuth Access Token");
+        assert_eq!(result[0].value, token);
+    }
+
+    #[test]
+    fn test_refresh_token_in_credentials_json() {
+        let token = "1//0gLq3vR7xZ2kP9mN4tY8wE1uI5oP0aS6dF3gH9jK2lZ7xC4vB1nM8qW5eR0tY3uI6oPzXcV";
+        let credentials = format!(
+            r#"{{
+  "client_id": "764086051850-6qr4p6gpi6hn506pt8ejuq83di341hur.apps.googleusercontent.com",
+  "client_secret": "d-FL95Q19q7MQmFpd7hHD0Ty",
+  "refresh_token": "{token}",
+  "type": "authorized_user"
+}}"#
+        );
+        let result = detect_google_credentials(&credentials);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Google OAuth Refresh Token");
+        assert_eq!(result[0].value, token);
+    }
+
+    #[test]
+    fn test_external_account_config() {
+        let config = r#"{
+  "type": "external_account",
+  "audience": "//iam.googleapis.com/projects/123456789/locations/global/workloadIdentityPools/github/providers/github-actions",
+  "subject_token_type": "urn:ietf:params:oauth:token-type:jwt",
+  "token_url": "https://sts.googleapis.com/v1/token",
+  "service_account_impersonation_url": "https://iamcredentials.googleapis.com/v1/projects/-/serviceAccounts/deployer@my-project.iam.gserviceaccount.com:generateAccessToken",
+  "credential_source": {
+    "file": "/var/run/secrets/token"
+  }
+}"#;
+        let result = detect_google_credentials(config);
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].secret_type,
+            "GCP Workload Identity Federation Config"
+        );
+        assert_eq!(
+            result[0].value,
+            "//iam.googleapis.com/projects/123456789/locations/global/workloadIdentityPools/github/providers/github-actions"
+        );
+    }
+
+    #[test]
+    fn test_external_account_without_impersonation_not_reported() {
+        let config = r#"{"type": "external_account", "audience": "//iam.googleapis.com/projects/1/locations/global/workloadIdentityPools/p/providers/q"}"#;
+        assert!(detect_google_credentials(config).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_google_tokens() {
+        assert!(detect_google_credentials("ya29.short").is_empty());
+        assert!(detect_google_credentials("1//0tooshort").is_empty());
+        assert!(detect_google_credentials(
+            "11//0gLq3vR7xZ2kP9mN4tY8wE1uI5oP0aS6dF3gH9jK2lZ7xC4vB1nM8qW5eR0"
+        )
+        .is_empty());
+        assert!(detect_google_credentials("").is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+use std::ops::Range;
+
+/// Returns the end of the JSON value starting at `start`, skipping nested strings
+///
+/// Strings end at their closing quote, objects and arrays at their matching bracket,
+/// and anything else, such as a number, at the next `,`, `}` or `]`.
+fn value_end(content: &str, start: usize) -> Option<usize> {
+    let bytes = content.as_bytes();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (offset, &byte) in bytes.iter().enumerate().skip(start) {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => {
+                    in_string = false;
+                    if depth == 0 {
+                        return Some(offset + 1);
+                    }
+                }
+                _ => {}
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' if depth == 0 => return Some(offset),
+            b'}' | b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(offset + 1);
+                }
+            }
+            b',' if depth == 0 => return Some(offset),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Finds the value of the first `"key": value` pair inside a span of a JSON document
+///
+/// This searches text rather than parsing, so the key may be at any depth of the span;
+/// pass the span of an object found earlier to stay inside it.
+///
+/// # Arguments
+/// * `content` - The JSON document
+/// * `key` - The key, without quotes, e.g. `scripts`
+/// * `within` - Byte range of `content` to search, e.g. `0..content.len()`
+///
+/// # Returns
+/// * `Option<Range<usize>>` - Byte range of the value, quotes and brackets included
+pub fn json_value_span(content: &str, key: &str, within: Range<usize>) -> Option<Range<usize>> {
+    let quoted = format!("\"{key}\"");
+    let mut from = within.start;
+    while let Some(found) = content.get(from..within.end)?.find(&quoted) {
+        let after_key = from + found + quoted.len();
+        let rest = &content[after_key..within.end];
+        if let Some(value) = rest.trim_start().strip_prefix(':') {
+            let start = within.end - value.trim_start().len();
+            let end = value_end(content, start)?.min(within.end);
+            return Some(start..end);
+        }
+        from = after_key;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_spans() {
+        let json = r#"{"name": "app", "scripts": {"test": "jest", "postinstall": "node \"x\" {"}, "n": 1}"#;
+        let scripts = json_value_span(json, "scripts", 0..json.len()).unwrap();
+        assert_eq!(
+            &json[scripts.clone()],
+            r#"{"test": "jest", "postinstall": "node \"x\" {"}"#
+        );
+        let hook = json_value_span(json, "postinstall", scripts).unwrap();
+        assert_eq!(&json[hook], r#""node \"x\" {""#);
+        let number = json_value_span(json, "n", 0..json.len()).unwrap();
+        assert_eq!(&json[number], "1");
+        assert_eq!(json_value_span(json, "missing", 0..json.len()), None);
+    }
+}
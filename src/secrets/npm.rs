@@ -1,35 +1,112 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use super::detector::Detector;
+use super::json::json_value_span;
+use super::secret_match::{Confidence, SecretMatch};
+use super::secret_type::SecretType;
+
 /// Regex pattern for NPM authToken detection
 /// Matches npmrc authToken patterns like:
 /// - //registry.npmjs.org/:_authToken=npm_xxxx
 /// - //registry.npmjs.org/:_authToken=xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx (UUID format)
+///
 /// ref. https://stackoverflow.com/questions/53099434/using-auth-tokens-in-npmrc
 static NPM_AUTH_TOKEN_PATTERN: Lazy<Regex> = Lazy::new(|| {
     Regex::new(r"//[^\s]+/:_authToken=\s*((npm_[A-Za-z0-9]+)|([A-Fa-f0-9-]{36}))")
         .expect("Invalid regex pattern")
 });
 
+/// Regex pattern for a `"key": "string"` pair of a JSON object
+/// Pattern captures: (1) the key, (2) the value, both without quotes
+static JSON_STRING_PAIR_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#""((?:[^"\\]|\\.)*)"\s*:\s*"((?:[^"\\]|\\.)*)""#).expect("Invalid regex pattern")
+});
+
+/// Endings of the npm config keys that hold registry credentials, e.g.
+/// `//registry.npmjs.org/:_authToken`
+const AUTH_KEY_SUFFIXES: &[&str] = &["_authToken", "_auth", "_password"];
+
+/// Detects registry credentials hardcoded in a package.json `publishConfig`
+///
+/// `publishConfig` takes any npm config key, so a token meant for `.npmrc` sometimes
+/// lands in the manifest itself and is published with every release. Values that read
+/// the token from the environment, like `${NPM_TOKEN}`, are left out.
+///
+/// # Arguments
+/// * `secret` - The string to check, usually a package.json
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_publish_config_tokens(secret: &str) -> Vec<SecretMatch> {
+    let Some(config) = json_value_span(secret, "publishConfig", 0..secret.len()) else {
+        return Vec::new();
+    };
+    JSON_STRING_PAIR_PATTERN
+        .captures_iter(&secret[config.clone()])
+        .filter(|pair| {
+            AUTH_KEY_SUFFIXES
+                .iter()
+                .any(|suffix| pair[1].ends_with(suffix))
+                && !pair[2].is_empty()
+                && !pair[2].contains("${")
+        })
+        .filter_map(|pair| pair.get(2))
+        .map(|value| {
+            SecretMatch::new(
+                SecretType::NpmToken,
+                value.as_str(),
+                config.start + value.start(),
+                config.start + value.end(),
+            )
+        })
+        .collect()
+}
+
 /// Detects all NPM auth tokens in a string
 ///
 /// # Arguments
 /// * `secret` - The string to check for NPM token patterns
 ///
 /// # Returns
-/// * `Vec<(String, String)>` - List of all (secret_type, value) pairs found
-pub fn detect_npm_tokens(secret: &str) -> Vec<(String, String)> {
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_npm_tokens(secret: &str) -> Vec<SecretMatch> {
     let mut tokens = Vec::new();
 
     for captures in NPM_AUTH_TOKEN_PATTERN.captures_iter(secret) {
         if let Some(token) = captures.get(1) {
-            tokens.push(("NPM Token".to_string(), token.as_str().to_string()));
+            tokens.push(SecretMatch::from_match(SecretType::NpmToken, token));
         }
     }
 
     tokens
 }
 
+/// Detector registered as `"npm"`; see [`detect_npm_tokens`] and
+/// [`detect_publish_config_tokens`]
+pub struct NpmDetector;
+
+impl Detector for NpmDetector {
+    fn name(&self) -> &str {
+        "npm"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        let mut found = detect_npm_tokens(content);
+        found.extend(detect_publish_config_tokens(content));
+        found.sort_by_key(|found| found.start);
+        found
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"/:_authToken=|publishConfig".to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,11 +116,19 @@ mod tests {
         // npm_xxx format token
         let result = detect_npm_tokens("//registry.npmjs.org/:_authToken=npm_abcdefg123456789");
         assert!(!result.is_empty());
-        let (secret_type, value) = result.first().unwrap();
+        let (secret_type, value) = result[0].clone().into_pair();
         assert_eq!(secret_type, "NPM Token");
         assert_eq!(value, "npm_abcdefg123456789");
     }
 
+    #[test]
+    fn test_npmrc_token_line_and_column() {
+        let npmrc = "registry=https://registry.npmjs.org/\r\n@acme:registry=https://npm.acme.dev/\r\n//npm.acme.dev/:_authToken=npm_abcdefg123456789\r\nalways-auth=true";
+        let result = detect_npm_tokens(npmrc);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].line_col(npmrc), (3, 28));
+    }
+
     #[test]
     fn test_valid_npm_token_uuid_format() {
         // UUID format token (36 characters including hyphens)
@@ -51,7 +136,7 @@ mod tests {
             "//registry.npmjs.org/:_authToken=12345678-1234-1234-1234-123456789abc",
         );
         assert!(!result.is_empty());
-        let (secret_type, value) = result.first().unwrap();
+        let (secret_type, value) = result[0].clone().into_pair();
         assert_eq!(secret_type, "NPM Token");
         assert_eq!(value, "12345678-1234-1234-1234-123456789abc");
     }
@@ -61,7 +146,7 @@ mod tests {
         // With space after equals sign
         let result = detect_npm_tokens("//registry.npmjs.org/:_authToken= npm_token123");
         assert!(!result.is_empty());
-        assert_eq!(result.first().unwrap().1, "npm_token123");
+        assert_eq!(result.first().unwrap().value, "npm_token123");
     }
 
     #[test]
@@ -69,7 +154,7 @@ mod tests {
         // Custom registry URL
         let result = detect_npm_tokens("//npm.mycompany.com/:_authToken=npm_customtoken123");
         assert!(!result.is_empty());
-        assert_eq!(result.first().unwrap().1, "npm_customtoken123");
+        assert_eq!(result.first().unwrap().value, "npm_customtoken123");
     }
 
     #[test]
@@ -78,7 +163,7 @@ mod tests {
         let npmrc_content = "@myorg:registry=https://npm.mycompany.com/\n//npm.mycompany.com/:_authToken=npm_abcd1234efgh5678";
         let result = detect_npm_tokens(npmrc_content);
         assert!(!result.is_empty());
-        assert_eq!(result.first().unwrap().1, "npm_abcd1234efgh5678");
+        assert_eq!(result.first().unwrap().value, "npm_abcd1234efgh5678");
     }
 
     #[test]
@@ -87,8 +172,8 @@ mod tests {
             "//registry.npmjs.org/:_authToken=npm_token1\n//npm.company.com/:_authToken=npm_token2";
         let results = detect_npm_tokens(content);
         assert_eq!(results.len(), 2);
-        assert_eq!(results[0].1, "npm_token1");
-        assert_eq!(results[1].1, "npm_token2");
+        assert_eq!(results[0].value, "npm_token1");
+        assert_eq!(results[1].value, "npm_token2");
     }
 
     #[test]
@@ -120,4 +205,27 @@ mod tests {
         );
         assert!(!result.is_empty());
     }
+
+    #[test]
+    fn test_publish_config_tokens() {
+        let manifest = r#"{
+  "name": "@acme/client",
+  "publishConfig": {
+    "registry": "https://npm.acme.dev/",
+    "//npm.acme.dev/:_authToken": "npm_abcd1234efgh5678"
+  },
+  "config": { "_authToken": "not-in-publish-config" }
+}"#;
+        let result = NpmDetector.detect(manifest);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "NPM Token");
+        assert_eq!(result[0].value, "npm_abcd1234efgh5678");
+        assert_eq!(
+            &manifest[result[0].start..result[0].end],
+            "npm_abcd1234efgh5678"
+        );
+
+        let from_env = r#"{"publishConfig": {"_authToken": "${NPM_TOKEN}"}}"#;
+        assert!(detect_publish_config_tokens(from_env).is_empty());
+    }
 }
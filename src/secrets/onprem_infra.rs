@@ -0,0 +1,199 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::detector::Detector;
+use super::secret_match::SecretMatch;
+use super::secret_type::SecretType;
+use super::yaml::yaml_scalar_entries;
+
+/// Regex pattern for Proxmox VE API token authorization headers
+/// Format: PVEAPIToken=user@realm!tokenid=<uuid>
+/// Pattern captures: (1) the token UUID
+static PROXMOX_API_TOKEN_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"PVEAPIToken=[A-Za-z0-9._-]+@[A-Za-z0-9._-]+![A-Za-z0-9._-]+=([0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12})\b",
+    )
+    .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for govc password environment variables
+/// Pattern captures: (1) the password
+static GOVC_PASSWORD_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\bGOVC_PASSWORD['"]?\s*[=:]\s*['"]?([^\s'"]+)"#).expect("Invalid regex pattern")
+});
+
+/// Regex pattern for PowerCLI cmdlets that take a plaintext -Password argument
+/// Pattern captures: (1) the password
+static POWERCLI_PASSWORD_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)(?:Connect-VIServer|New-VICredentialStoreItem)\b[^\n]*?-Password\s+['"]?([^\s'"]+)"#,
+    )
+    .expect("Invalid regex pattern")
+});
+
+/// clouds.yaml key paths that hold OpenStack secrets, with their labels
+static OPENSTACK_SECRET_KEYS: &[(&str, SecretType)] = &[
+    ("password", SecretType::OpenStackPassword),
+    (
+        "application_credential_secret",
+        SecretType::OpenStackApplicationCredentialSecret,
+    ),
+];
+
+/// Checks whether a value is a variable reference rather than a literal
+fn is_variable_reference(value: &str) -> bool {
+    value.starts_with('$') || value.starts_with("{{")
+}
+
+/// Detects OpenStack secrets in clouds.yaml documents
+///
+/// Only keys under `clouds.<cloud>.auth` are considered, so unrelated
+/// `password:` keys elsewhere in a YAML file are not reported.
+fn detect_openstack_clouds_yaml(content: &str) -> Vec<SecretMatch> {
+    let mut secrets = Vec::new();
+
+    for entry in yaml_scalar_entries(content) {
+        if entry.path.len() != 4 || entry.path[0] != "clouds" {
+            continue;
+        }
+        let Some((_, label)) = OPENSTACK_SECRET_KEYS
+            .iter()
+            .find(|(key, _)| entry.path_ends_with(&["auth", key]))
+        else {
+            continue;
+        };
+        if !is_variable_reference(entry.value) {
+            secrets.push(SecretMatch::from_slice(label.clone(), content, entry.value));
+        }
+    }
+
+    secrets
+}
+
+/// Detects on-premises infrastructure credentials
+///
+/// Covers:
+/// - OpenStack clouds.yaml auth passwords and application credential secrets
+/// - Proxmox VE API tokens in `PVEAPIToken=user@realm!tokenid=<uuid>` headers
+/// - VMware vSphere passwords in `GOVC_PASSWORD` and PowerCLI `-Password` arguments
+///
+/// # Arguments
+/// * `content` - The string to check for on-premises infrastructure credentials
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_onprem_infra_credentials(content: &str) -> Vec<SecretMatch> {
+    let mut secrets = detect_openstack_clouds_yaml(content);
+
+    for captures in PROXMOX_API_TOKEN_PATTERN.captures_iter(content) {
+        if let Some(token) = captures.get(1) {
+            secrets.push(SecretMatch::from_match(SecretType::ProxmoxApiToken, token));
+        }
+    }
+
+    for pattern in [&*GOVC_PASSWORD_PATTERN, &*POWERCLI_PASSWORD_PATTERN] {
+        for captures in pattern.captures_iter(content) {
+            if let Some(password) = captures.get(1) {
+                if !is_variable_reference(password.as_str()) {
+                    secrets.push(SecretMatch::from_match(
+                        SecretType::VmwareVspherePassword,
+                        password,
+                    ));
+                }
+            }
+        }
+    }
+
+    secrets
+}
+
+/// Detector registered as `"onprem_infra"`; see [`detect_onprem_infra_credentials`]
+pub struct OnPremInfraDetector;
+
+impl Detector for OnPremInfraDetector {
+    fn name(&self) -> &str {
+        "onprem_infra"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_onprem_infra_credentials(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(
+            r"clouds|PVEAPIToken=|GOVC_PASSWORD|(?i:connect-viserver|new-vicredentialstoreitem)"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clouds_yaml_with_two_clouds() {
+        let clouds = "clouds:
+  production:
+    auth:
+      auth_url: https://keystone.example.com:5000/v3
+      username: deployer
+      password: Pr0dOpenstackPass!
+      project_name: infra
+    region_name: RegionOne
+  staging:
+    auth_type: v3applicationcredential
+    auth:
+      auth_url: https://keystone.staging.example.com:5000/v3
+      application_credential_id: 21dced0fd20347869b93710d2b98aae0
+      application_credential_secret: rLx1Ylg8b2K7V9qWmN3pT6sH0fJ4dC5e
+";
+        let result = detect_onprem_infra_credentials(clouds);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].secret_type, "OpenStack Password");
+        assert_eq!(result[0].value, "Pr0dOpenstackPass!");
+        assert_eq!(
+            result[1].secret_type,
+            "OpenStack Application Credential Secret"
+        );
+        assert_eq!(result[1].value, "rLx1Ylg8b2K7V9qWmN3pT6sH0fJ4dC5e");
+    }
+
+    #[test]
+    fn test_password_outside_clouds_auth_not_reported() {
+        let yaml = "database:\n  password: hunter22\nclouds:\n  prod:\n    password: nope\n";
+        assert!(detect_onprem_infra_credentials(yaml).is_empty());
+    }
+
+    #[test]
+    fn test_curl_with_proxmox_api_token() {
+        let curl = r#"curl -k -H "Authorization: PVEAPIToken=root@pam!monitoring=aaaabbbb-cccc-dddd-eeee-ffffgggghhhh" https://pve:8006/api2/json/nodes
+curl -k -H "Authorization: PVEAPIToken=automation@pve!ci=5f3c2a1e-8b7d-4c6e-9a0f-1d2e3f4a5b6c" https://pve:8006/api2/json/nodes"#;
+        let result = detect_onprem_infra_credentials(curl);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Proxmox API Token");
+        assert_eq!(result[0].value, "5f3c2a1e-8b7d-4c6e-9a0f-1d2e3f4a5b6c");
+    }
+
+    #[test]
+    fn test_govc_password_export() {
+        let env = "export GOVC_URL=https://vcenter.example.com/sdk\nexport GOVC_USERNAME=administrator@vsphere.local\nexport GOVC_PASSWORD='VMw@re-Adm1n!'\n";
+        let result = detect_onprem_infra_credentials(env);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "VMware vSphere Password");
+        assert_eq!(result[0].value, "VMw@re-Adm1n!");
+    }
+
+    #[test]
+    fn test_powercli_connect_password() {
+        let script = "Connect-VIServer -Server vcenter.example.com -User admin -Password 'P0werCli!Pass'\nConnect-VIServer -Server vc -Credential $cred -Password $env:VC_PASS";
+        let result = detect_onprem_infra_credentials(script);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].value, "P0werCli!Pass");
+    }
+
+    #[test]
+    fn test_govc_password_reference_not_reported() {
+        assert!(detect_onprem_infra_credentials("GOVC_PASSWORD=${VC_PASSWORD}").is_empty());
+    }
+}
@@ -1,35 +1,81 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use super::detector::Detector;
+use super::prescreen::{Anchor, Reach};
+use super::secret_match::{Confidence, Liveness, SecretMatch};
+use super::secret_type::SecretType;
+
 /// Regex patterns for PyPI token detection
-/// Matches both pypi.org and test.pypi.org token formats
-static PYPI_TOKEN_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| {
+/// Matches pypi.org tokens, then test.pypi.org tokens, with the liveness of each
+static PYPI_TOKEN_PATTERNS: Lazy<Vec<(Regex, Liveness)>> = Lazy::new(|| {
     vec![
-        Regex::new(r"pypi-AgEIcHlwaS5vcmc[A-Za-z0-9-_]{70,}").expect("Invalid regex pattern"),
-        Regex::new(r"pypi-AgENdGVzdC5weXBpLm9yZw[A-Za-z0-9-_]{70,}")
-            .expect("Invalid regex pattern"),
+        (
+            Regex::new(r"pypi-AgEIcHlwaS5vcmc[A-Za-z0-9-_]{70,}").expect("Invalid regex pattern"),
+            Liveness::Live,
+        ),
+        (
+            Regex::new(r"pypi-AgENdGVzdC5weXBpLm9yZw[A-Za-z0-9-_]{70,}")
+                .expect("Invalid regex pattern"),
+            Liveness::Test,
+        ),
     ]
 });
 
 /// Detects all PyPI tokens in a string
 ///
+/// Tokens issued by test.pypi.org are reported with [`Liveness::Test`].
+///
 /// # Arguments
 /// * `secret` - The string to check for PyPI token patterns
 ///
 /// # Returns
-/// * `Vec<(String, String)>` - List of all (secret_type, value) pairs found
-pub fn detect_pypi_tokens(secret: &str) -> Vec<(String, String)> {
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_pypi_tokens(secret: &str) -> Vec<SecretMatch> {
     let mut tokens = Vec::new();
 
-    for pattern in PYPI_TOKEN_PATTERNS.iter() {
+    for (pattern, liveness) in PYPI_TOKEN_PATTERNS.iter() {
         for token_match in pattern.find_iter(secret) {
-            tokens.push(("PyPI Token".to_string(), token_match.as_str().to_string()));
+            tokens.push(
+                SecretMatch::from_match(SecretType::PyPiToken, token_match)
+                    .with_liveness(*liveness),
+            );
         }
     }
 
+    tokens.sort_by_key(|token| token.start);
     tokens
 }
 
+/// Detector registered as `"pypi"`; see [`detect_pypi_tokens`]
+pub struct PyPiDetector;
+
+impl Detector for PyPiDetector {
+    fn name(&self) -> &str {
+        "pypi"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_pypi_tokens(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"pypi-AgE".to_string())
+    }
+
+    fn anchor(&self) -> Option<Anchor> {
+        Some(Anchor::new(
+            &["pypi-AgEIcHlwaS5vcmc", "pypi-AgENdGVzdC5weXBpLm9yZw"],
+            Reach::Bytes(0),
+            Reach::Line,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,9 +89,10 @@ mod tests {
         let token = build_token("pypi-AgEIcHlwaS5vcmc");
         let result = detect_pypi_tokens(&token);
         assert!(!result.is_empty());
-        let (secret_type, value) = result.first().unwrap();
+        assert_eq!(result[0].liveness, Liveness::Live);
+        let (secret_type, value) = result[0].clone().into_pair();
         assert_eq!(secret_type, "PyPI Token");
-        assert_eq!(value, &token);
+        assert_eq!(value, token);
     }
 
     #[test]
@@ -53,7 +100,8 @@ mod tests {
         let token = build_token("pypi-AgENdGVzdC5weXBpLm9yZw");
         let result = detect_pypi_tokens(&token);
         assert!(!result.is_empty());
-        assert_eq!(result.first().unwrap().1, token);
+        assert_eq!(result.first().unwrap().value, token);
+        assert_eq!(result[0].liveness, Liveness::Test);
     }
 
     #[test]
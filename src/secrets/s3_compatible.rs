@@ -0,0 +1,270 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::context::context_window;
+use super::detector::Detector;
+use super::secret_match::SecretMatch;
+use super::secret_type::SecretType;
+
+/// S3-compatible storage vendors recognized by their endpoint host
+/// Each entry is (host fragment, vendor label); adding a vendor is a one-line change
+pub static S3_COMPATIBLE_ENDPOINTS: &[(&str, &str)] = &[
+    ("wasabisys.com", "Wasabi"),
+    ("backblazeb2.com", "Backblaze B2"),
+    ("digitaloceanspaces.com", "DigitalOcean Spaces"),
+    ("minio", "MinIO"),
+];
+
+/// Number of bytes on either side of a key searched for an endpoint
+const ENDPOINT_CONTEXT_WINDOW: usize = 256;
+
+/// Regex pattern for endpoint hosts, either in a URL or an `endpoint` assignment
+/// Pattern captures: (1) the host
+static ENDPOINT_HOST_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)(?:https?://|endpoint['"]?\s*[=:]\s*['"]?)([a-z0-9.-]+)"#)
+        .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for AWS-shaped access key IDs assigned to an access_key field
+/// Pattern captures: (1) the 20 character access key ID
+static ACCESS_KEY_ID_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i:access_key(?:_id)?)['"]?\s*[=:]\s*['"]?([A-Z0-9]{20})\b"#)
+        .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for MinIO root credentials in environment assignments
+/// Pattern captures: (1) the variable name, (2) the value
+static MINIO_CREDENTIAL_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\b(MINIO_ROOT_PASSWORD|MINIO_SECRET_KEY)['"]?\s*[=:]\s*['"]?([^\s'"]{8,})"#)
+        .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for Backblaze B2 application keys
+/// Format: K + 3 digits + 27 base64 characters (31 characters total)
+static B2_APPLICATION_KEY_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|[^A-Za-z0-9+/])(K\d{3}[A-Za-z0-9+/]{27})(?:$|[^A-Za-z0-9+/])")
+        .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for Backblaze B2 application key IDs
+/// Format: 0 + 24 hex characters
+static B2_KEY_ID_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b0[0-9a-f]{24}\b").expect("Invalid regex pattern"));
+
+/// Regex pattern for a 31 character key assigned next to a B2 key ID
+/// Pattern captures: (1) the application key
+static B2_PAIRED_KEY_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i:application_?key|app_?key|\bkey)['"]?\s*[=:]\s*['"]?([A-Za-z0-9+/]{31})(?:$|[^A-Za-z0-9+/])"#,
+    )
+    .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for b2://keyID:applicationKey@bucket URIs
+/// Pattern captures: (1) the application key
+static B2_URI_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"b2://0[0-9a-f]{24}:([A-Za-z0-9+/]{31})@").expect("Invalid regex pattern")
+});
+
+/// Finds the S3-compatible vendor whose endpoint appears near a match
+///
+/// # Arguments
+/// * `content` - The full string that was scanned
+/// * `start` - Byte offset where the match starts
+/// * `end` - Byte offset where the match ends
+///
+/// # Returns
+/// * `Option<&'static str>` - The vendor label if a known endpoint is within the context window
+pub fn s3_compatible_vendor_near(content: &str, start: usize, end: usize) -> Option<&'static str> {
+    let window = context_window(content, start, end, ENDPOINT_CONTEXT_WINDOW);
+
+    ENDPOINT_HOST_PATTERN
+        .captures_iter(window)
+        .filter_map(|captures| captures.get(1))
+        .find_map(|host| {
+            let host = host.as_str().to_ascii_lowercase();
+            S3_COMPATIBLE_ENDPOINTS
+                .iter()
+                .find(|(fragment, _)| host.contains(fragment))
+                .map(|(_, vendor)| *vendor)
+        })
+}
+
+/// Detects credentials for S3-compatible storage vendors
+///
+/// Covers:
+/// - MinIO root credentials (MINIO_ROOT_PASSWORD / MINIO_SECRET_KEY assignments)
+/// - Backblaze B2 application keys (K-prefixed keys, keys paired with a key ID, b2:// URIs)
+/// - AWS-shaped access key IDs next to a known S3-compatible endpoint
+///
+/// AWS-shaped secret keys next to these endpoints are relabeled by the AWS detector.
+///
+/// # Arguments
+/// * `content` - The string to check for S3-compatible credentials
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_s3_compatible_credentials(content: &str) -> Vec<SecretMatch> {
+    let mut secrets: Vec<SecretMatch> = Vec::new();
+
+    for captures in MINIO_CREDENTIAL_PATTERN.captures_iter(content) {
+        if let (Some(name), Some(value)) = (captures.get(1), captures.get(2)) {
+            if value.as_str().starts_with('$') {
+                continue;
+            }
+            let secret_type = match name.as_str() {
+                "MINIO_ROOT_PASSWORD" => SecretType::MinioRootPassword,
+                _ => SecretType::MinioSecretKey,
+            };
+            secrets.push(SecretMatch::from_match(secret_type, value));
+        }
+    }
+
+    let mut b2_keys: Vec<&str> = Vec::new();
+    for captures in B2_APPLICATION_KEY_PATTERN.captures_iter(content) {
+        if let Some(key) = captures.get(1) {
+            b2_keys.push(key.as_str());
+        }
+    }
+    for captures in B2_URI_PATTERN.captures_iter(content) {
+        if let Some(key) = captures.get(1) {
+            b2_keys.push(key.as_str());
+        }
+    }
+    for key_id in B2_KEY_ID_PATTERN.find_iter(content) {
+        let window = context_window(
+            content,
+            key_id.start(),
+            key_id.end(),
+            ENDPOINT_CONTEXT_WINDOW,
+        );
+        for captures in B2_PAIRED_KEY_PATTERN.captures_iter(window) {
+            if let Some(key) = captures.get(1) {
+                b2_keys.push(key.as_str());
+            }
+        }
+    }
+    for key in b2_keys {
+        let found = SecretMatch::from_slice(SecretType::BackblazeB2ApplicationKey, content, key);
+        if !secrets
+            .iter()
+            .any(|other| other.start < found.end && found.start < other.end)
+        {
+            secrets.push(found);
+        }
+    }
+
+    for captures in ACCESS_KEY_ID_PATTERN.captures_iter(content) {
+        if let Some(key) = captures.get(1) {
+            if let Some(vendor) = s3_compatible_vendor_near(content, key.start(), key.end()) {
+                secrets.push(SecretMatch::from_match(
+                    SecretType::S3CompatibleAccessKey(vendor),
+                    key,
+                ));
+            }
+        }
+    }
+
+    secrets
+}
+
+/// Detector registered as `"s3_compatible"`; see [`detect_s3_compatible_credentials`]
+pub struct S3CompatibleDetector;
+
+impl Detector for S3CompatibleDetector {
+    fn name(&self) -> &str {
+        "s3_compatible"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_s3_compatible_credentials(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"MINIO_ROOT_PASSWORD|MINIO_SECRET_KEY|K\d{3}[A-Za-z0-9+/]{27}|b2://|0[0-9a-f]{24}|(?i:access_key)".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RCLONE_CONF: &str = "[b2]
+type = b2
+account = 001a2b3c4d5e6f70000000003
+key = K001aBcDeFgHiJkLmNoPqRsTuVwXyZ0
+
+[wasabi]
+type = s3
+provider = Wasabi
+access_key_id = WASABIACCESSKEY12345
+secret_access_key = wJalrXUtnFEMI/K7MDENG/bPxRfiCYWASABIKEYX
+endpoint = s3.wasabisys.com
+";
+
+    #[test]
+    fn test_rclone_conf_with_b2_and_wasabi_remotes() {
+        let result = detect_s3_compatible_credentials(RCLONE_CONF);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].secret_type, "Backblaze B2 Application Key");
+        assert_eq!(result[0].value, "K001aBcDeFgHiJkLmNoPqRsTuVwXyZ0");
+        assert_eq!(result[1].secret_type, "Wasabi Access Key");
+        assert_eq!(result[1].value, "WASABIACCESSKEY12345");
+    }
+
+    #[test]
+    fn test_wasabi_endpoint_lookup() {
+        let start = RCLONE_CONF.find("wJalr").unwrap();
+        assert_eq!(
+            s3_compatible_vendor_near(RCLONE_CONF, start, start + 40),
+            Some("Wasabi")
+        );
+        assert_eq!(s3_compatible_vendor_near("secret = abc", 9, 12), None);
+    }
+
+    #[test]
+    fn test_b2_key_paired_with_key_id() {
+        let env = "B2_APPLICATION_KEY_ID=001a2b3c4d5e6f70000000003\nB2_APPLICATION_KEY=abcdefghijklmnopqrstuvwxyz01234\n";
+        let result = detect_s3_compatible_credentials(env);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Backblaze B2 Application Key");
+        assert_eq!(result[0].value, "abcdefghijklmnopqrstuvwxyz01234");
+    }
+
+    #[test]
+    fn test_b2_uri() {
+        let uri =
+            "duplicity b2://001a2b3c4d5e6f70000000003:abcdefghijklmnopqrstuvwxyz01234@backups/";
+        let result = detect_s3_compatible_credentials(uri);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].value, "abcdefghijklmnopqrstuvwxyz01234");
+    }
+
+    #[test]
+    fn test_docker_compose_minio_root_password() {
+        let compose = "services:
+  minio:
+    image: minio/minio
+    environment:
+      MINIO_ROOT_USER: admin
+      MINIO_ROOT_PASSWORD: Sup3rS3cretMinio!
+";
+        let result = detect_s3_compatible_credentials(compose);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "MinIO Root Password");
+        assert_eq!(result[0].value, "Sup3rS3cretMinio!");
+    }
+
+    #[test]
+    fn test_minio_password_reference_not_reported() {
+        let compose =
+            "      - MINIO_ROOT_PASSWORD=${MINIO_PASSWORD}\n      - MINIO_ROOT_PASSWORD=short";
+        assert!(detect_s3_compatible_credentials(compose).is_empty());
+    }
+
+    #[test]
+    fn test_access_key_without_endpoint_not_reported() {
+        let conf = "access_key_id = WASABIACCESSKEY12345";
+        assert!(detect_s3_compatible_credentials(conf).is_empty());
+    }
+}
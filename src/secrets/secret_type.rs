@@ -0,0 +1,310 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::s3_compatible::S3_COMPATIBLE_ENDPOINTS;
+use super::secret_match::Confidence;
+
+/// Declares the fixed secret types together with their reported names
+///
+/// Each entry expands to a unit variant of [`SecretType`] and a row in `NAMED_TYPES`,
+/// so the name used by `Display` and `FromStr` is written exactly once.
+macro_rules! named_secret_types {
+    ($($variant:ident => $name:literal,)+) => {
+        /// The kind of secret a detector reported
+        ///
+        /// `Display` produces the human-readable name that detectors have always
+        /// reported (e.g. "GitLab Token"), and `FromStr` parses it back.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum SecretType {
+            $($variant,)+
+            /// An AWS-shaped access key ID next to a known S3-compatible vendor endpoint
+            S3CompatibleAccessKey(&'static str),
+            /// An AWS-shaped secret key next to a known S3-compatible vendor endpoint
+            S3CompatibleSecretKey(&'static str),
+            /// A truncated token of the wrapped type
+            Partial(Box<SecretType>),
+            /// A label from a user-defined context rule
+            Custom(String),
+        }
+
+        /// Fixed secret types and their reported names
+        const NAMED_TYPES: &[(SecretType, &str)] = &[$((SecretType::$variant, $name),)+];
+    };
+}
+
+named_secret_types! {
+    AwsAccessKeyId => "AWS Access Key ID",
+    AwsSecretAccessKey => "AWS Secret Access Key",
+    CanaryToken => "Canary Token",
+    OpenAiToken => "OpenAI Token",
+    AnthropicApiKey => "Anthropic API Key",
+    JwtToken => "JWT Token",
+    GitHubActionsToken => "GitHub Actions Token",
+    GitHubInstallationToken => "GitHub Installation Token",
+    PersistedGitHubToken => "Persisted GITHUB_TOKEN",
+    GitHubPersonalAccessToken => "GitHub Personal Access Token",
+    GitHubOAuthToken => "GitHub OAuth Token",
+    GitHubFineGrainedToken => "GitHub Fine-Grained Personal Access Token",
+    PrivateKey => "Private Key",
+    SshHostPrivateKey => "SSH Host Private Key",
+    PuttyPrivateKey => "PuTTY Private Key",
+    EncryptedPuttyPrivateKey => "PuTTY Private Key (Encrypted)",
+    BasicAuthCredentials => "Basic Auth Credentials",
+    GitStoredCredential => "Git Stored Credential",
+    NpmToken => "NPM Token",
+    DiscordBotToken => "Discord Bot Token",
+    PyPiToken => "PyPI Token",
+    SlackToken => "Slack Token",
+    SlackBotToken => "Slack Bot Token",
+    SlackUserToken => "Slack User Token",
+    SlackSessionToken => "Slack Session Token",
+    SlackRefreshToken => "Slack Refresh Token",
+    SlackWebhookUrl => "Slack Webhook URL",
+    DiscordWebhookUrl => "Discord Webhook URL",
+    TeamsWebhookUrl => "Microsoft Teams Webhook URL",
+    StripeAccessKey => "Stripe Access Key",
+    StripeWebhookSigningSecret => "Stripe Webhook Signing Secret",
+    SquareAccessToken => "Square Access Token",
+    SquareOAuthSecret => "Square OAuth Secret",
+    GitHubWebhookSecret => "GitHub Webhook Secret",
+    WebhookSigningSecret => "Webhook Signing Secret",
+    TwilioApiKey => "Twilio API Key",
+    TwilioAuthToken => "Twilio Auth Token",
+    GitLabToken => "GitLab Token",
+    DigitalOceanApiKey => "DigitalOcean API Key",
+    BrowserExtensionOAuthClientSecret => "Browser Extension OAuth Client Secret",
+    ChromeWebStoreClientSecret => "Chrome Web Store Client Secret",
+    ChromeWebStoreRefreshToken => "Chrome Web Store Refresh Token",
+    FirefoxAddonsApiSecret => "Firefox Add-ons API Secret",
+    GoogleOAuthRefreshToken => "Google OAuth Refresh Token",
+    GoogleOAuthAccessToken => "Google OAuth Access Token",
+    GoogleOAuthClientId => "Google OAuth Client ID",
+    GcpWorkloadIdentityFederationConfig => "GCP Workload Identity Federation Config",
+    MinioRootPassword => "MinIO Root Password",
+    MinioSecretKey => "MinIO Secret Key",
+    BackblazeB2ApplicationKey => "Backblaze B2 Application Key",
+    TotpSecret => "TOTP Secret",
+    HasuraAdminSecret => "Hasura Admin Secret",
+    GraphQlEndpointToken => "GraphQL Endpoint Token",
+    OpenStackPassword => "OpenStack Password",
+    OpenStackApplicationCredentialSecret => "OpenStack Application Credential Secret",
+    ProxmoxApiToken => "Proxmox API Token",
+    VmwareVspherePassword => "VMware vSphere Password",
+    KubernetesBootstrapToken => "Kubernetes Bootstrap Token",
+    ClusterCaPrivateKey => "Cluster CA Private Key",
+    ClusterPrivateKey => "Cluster Private Key",
+    Rke2K3sClusterToken => "RKE2/K3s Cluster Token",
+    PasswordHash => "Password Hash",
+    PlaintextPasswordInDatabaseDump => "Plaintext Password in Database Dump",
+    PlaintextSecretInDatabaseDump => "Plaintext Secret in Database Dump",
+    AlgoliaApiKey => "Algolia API Key",
+    DatadogApiKey => "Datadog API Key",
+    DatadogApplicationKey => "Datadog Application Key",
+    FileTransferPassword => "File Transfer Password",
+    WinScpPassword => "WinSCP Password (Reversible Encryption)",
+    CargoRegistryToken => "Cargo Registry Token",
+    BazelRemoteHeaderSecret => "Bazel Remote Header Secret",
+    GoGenerateDirectiveSecret => "Go Generate Directive Secret",
+    MakefileRecipeSecret => "Makefile Recipe Secret",
+    FirebaseIosApiKey => "Firebase iOS API Key",
+    IosPlistSecret => "iOS Plist Secret",
+    XcodeBuildSettingSecret => "Xcode Build Setting Secret",
+    HerokuConfigVarSecret => "Heroku Config Var Secret",
+    FlyIoEnvSecret => "Fly.io Env Secret",
+    RenderEnvVarSecret => "Render Env Var Secret",
+    GitCredentialHelperPassword => "Git Credential Helper Password",
+    WindowsAutologonPassword => "Windows Autologon Password",
+    VncPassword => "VNC Password (Reversible Encryption)",
+    ScheduledTaskPassword => "Scheduled Task Password",
+    GroupPolicyPreferencesPassword => "Group Policy Preferences Password (Reversible Encryption)",
+    GcsSignedUrlSignature => "GCS Signed URL Signature",
+    CloudFrontSignedUrlSignature => "CloudFront Signed URL Signature",
+    CloudFrontSignedCookieSignature => "CloudFront Signed Cookie Signature",
+    OidcClientSecret => "OIDC Client Secret",
+    OidcRegistrationAccessToken => "OIDC Registration Access Token",
+    PrivateJwk => "Private JWK",
+    DpapiProtectedBlob => "DPAPI Protected Blob",
+    ChromeLocalStateEncryptedKey => "Chrome Local State Encrypted Key",
+    GenericSecret => "Generic Secret",
+}
+
+/// Suffix appended to the name of a truncated token's type
+const PARTIAL_SUFFIX: &str = " (Partial)";
+
+/// Error returned when parsing a name that no detector reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownSecretType(pub String);
+
+impl fmt::Display for UnknownSecretType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown secret type: {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownSecretType {}
+
+impl SecretType {
+    /// Wraps this type as the type of a truncated token
+    pub fn partial(self) -> Self {
+        SecretType::Partial(Box::new(self))
+    }
+
+    /// Returns the confidence a match of this type starts with
+    ///
+    /// Vendor-prefixed tokens and key blocks are High; values that are only a loose
+    /// shape, like a bare 40-character AWS secret key or a truncated token, are Low.
+    /// Everything else, including structural formats and values found through nearby
+    /// keywords, is Medium. Webhook signing secrets are High even when found through a
+    /// keyword, since a leak lets anyone forge events the receiver trusts; webhook URLs
+    /// only let someone post to a channel, and stay Medium. GitHub installation tokens
+    /// expire with the workflow job that was issued them and stay Medium, unless found in
+    /// a file, where they are reported as the High "Persisted GITHUB_TOKEN".
+    pub fn confidence(&self) -> Confidence {
+        match self {
+            SecretType::AwsAccessKeyId
+            | SecretType::CanaryToken
+            | SecretType::OpenAiToken
+            | SecretType::AnthropicApiKey
+            | SecretType::GitHubActionsToken
+            | SecretType::PersistedGitHubToken
+            | SecretType::GitHubFineGrainedToken
+            | SecretType::PrivateKey
+            | SecretType::SshHostPrivateKey
+            | SecretType::PuttyPrivateKey
+            | SecretType::EncryptedPuttyPrivateKey
+            | SecretType::NpmToken
+            | SecretType::PyPiToken
+            | SecretType::SlackToken
+            | SecretType::SlackBotToken
+            | SecretType::SlackUserToken
+            | SecretType::SlackSessionToken
+            | SecretType::SlackRefreshToken
+            | SecretType::StripeAccessKey
+            | SecretType::StripeWebhookSigningSecret
+            | SecretType::SquareAccessToken
+            | SecretType::SquareOAuthSecret
+            | SecretType::GitHubWebhookSecret
+            | SecretType::WebhookSigningSecret
+            | SecretType::GitLabToken
+            | SecretType::DigitalOceanApiKey
+            | SecretType::GoogleOAuthAccessToken
+            | SecretType::FirebaseIosApiKey
+            | SecretType::ClusterCaPrivateKey
+            | SecretType::ClusterPrivateKey
+            | SecretType::PrivateJwk
+            | SecretType::GcsSignedUrlSignature
+            | SecretType::CloudFrontSignedUrlSignature
+            | SecretType::CloudFrontSignedCookieSignature => Confidence::High,
+            // DPAPI blobs are encrypted to a Windows account; finding one is a lead, not a leak
+            SecretType::AwsSecretAccessKey
+            | SecretType::DpapiProtectedBlob
+            | SecretType::ChromeLocalStateEncryptedKey
+            | SecretType::Partial(_) => Confidence::Low,
+            _ => Confidence::Medium,
+        }
+    }
+}
+
+impl fmt::Display for SecretType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretType::S3CompatibleAccessKey(vendor) => write!(f, "{vendor} Access Key"),
+            SecretType::S3CompatibleSecretKey(vendor) => write!(f, "{vendor} Secret Key"),
+            SecretType::Partial(inner) => write!(f, "{inner}{PARTIAL_SUFFIX}"),
+            SecretType::Custom(name) => f.write_str(name),
+            named => {
+                let (_, name) = NAMED_TYPES
+                    .iter()
+                    .find(|(secret_type, _)| secret_type == named)
+                    .expect("every unit variant is listed in NAMED_TYPES");
+                f.write_str(name)
+            }
+        }
+    }
+}
+
+impl FromStr for SecretType {
+    type Err = UnknownSecretType;
+
+    /// Parses a reported name back into its type
+    ///
+    /// Fixed names win over vendor-derived ones, so "MinIO Secret Key" parses as
+    /// [`SecretType::MinioSecretKey`]. Labels of custom rules are not recognized;
+    /// construct [`SecretType::Custom`] for those.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        if let Some((secret_type, _)) = NAMED_TYPES.iter().find(|(_, known)| *known == name) {
+            return Ok(secret_type.clone());
+        }
+        if let Some(inner) = name.strip_suffix(PARTIAL_SUFFIX) {
+            return inner.parse().map(SecretType::partial);
+        }
+        for (_, vendor) in S3_COMPATIBLE_ENDPOINTS {
+            if let Some(rest) = name.strip_prefix(vendor) {
+                match rest {
+                    " Access Key" => return Ok(SecretType::S3CompatibleAccessKey(vendor)),
+                    " Secret Key" => return Ok(SecretType::S3CompatibleSecretKey(vendor)),
+                    _ => {}
+                }
+            }
+        }
+        Err(UnknownSecretType(name.to_string()))
+    }
+}
+
+/// Compares against the reported name, so existing string-based checks keep working
+impl PartialEq<&str> for SecretType {
+    fn eq(&self, other: &&str) -> bool {
+        self.to_string().as_str() == *other
+    }
+}
+
+/// Serializes as the reported name, e.g. `"GitLab Token"`
+#[cfg(feature = "serde")]
+impl serde::Serialize for SecretType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Parses a reported name; names no built-in detector reports become [`SecretType::Custom`]
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SecretType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(name.parse().unwrap_or(SecretType::Custom(name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_and_from_str_round_trip() {
+        for (secret_type, name) in NAMED_TYPES {
+            assert_eq!(secret_type.to_string(), *name);
+            assert_eq!(name.parse::<SecretType>().unwrap(), *secret_type);
+        }
+    }
+
+    #[test]
+    fn test_derived_types_round_trip() {
+        let partial = SecretType::GitLabToken.partial();
+        assert_eq!(partial.to_string(), "GitLab Token (Partial)");
+        assert_eq!("GitLab Token (Partial)".parse(), Ok(partial));
+
+        let wasabi = SecretType::S3CompatibleSecretKey("Wasabi");
+        assert_eq!(wasabi.to_string(), "Wasabi Secret Key");
+        assert_eq!("Wasabi Secret Key".parse(), Ok(wasabi));
+        assert_eq!("MinIO Secret Key".parse(), Ok(SecretType::MinioSecretKey));
+    }
+
+    #[test]
+    fn test_unknown_names_are_rejected() {
+        assert_eq!(
+            "Gitlab Token".parse::<SecretType>(),
+            Err(UnknownSecretType("Gitlab Token".to_string()))
+        );
+        assert!("Acme Token".parse::<SecretType>().is_err());
+    }
+}
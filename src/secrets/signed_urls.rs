@@ -0,0 +1,218 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::context::context_window;
+use super::detector::Detector;
+use super::secret_match::{Confidence, SecretMatch};
+use super::secret_type::SecretType;
+
+/// Number of bytes on either side of a CloudFront cookie signature searched for its key pair ID
+const COOKIE_CONTEXT_WINDOW: usize = 512;
+
+/// Regex pattern for the signature of a GCS V4 signed URL
+/// Format: 64 (HMAC) or 512 (RSA) hex characters
+/// Pattern captures: (1) the signature
+static GCS_SIGNATURE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[?&]X-Goog-Signature=([0-9a-fA-F]{64,})\b").expect("Invalid regex pattern")
+});
+
+/// Regex pattern for the signature of a CloudFront signed URL
+/// CloudFront uses URL-safe base64 (`-`, `_`, `~`); standard base64 arrives percent-encoded
+/// Pattern captures: (1) the signature as written in the URL
+static CLOUDFRONT_URL_SIGNATURE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[?&]Signature=([A-Za-z0-9\-_~%+/=]{40,})").expect("Invalid regex pattern")
+});
+
+/// Regex pattern for the signature cookie of CloudFront signed cookies
+/// Pattern captures: (1) the signature as written in the cookie
+static CLOUDFRONT_COOKIE_SIGNATURE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bCloudFront-Signature=([A-Za-z0-9\-_~%+/=]{40,})").expect("Invalid regex pattern")
+});
+
+/// Returns the URL surrounding a match, bounded by whitespace, quotes or angle brackets
+fn enclosing_url(content: &str, start: usize, end: usize) -> &str {
+    let is_boundary = |c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '<' | '>');
+    let url_start = content[..start].rfind(is_boundary).map_or(0, |i| {
+        i + content[i..].chars().next().map_or(1, char::len_utf8)
+    });
+    let url_end = content[end..]
+        .find(is_boundary)
+        .map_or(content.len(), |i| end + i);
+    &content[url_start..url_end]
+}
+
+/// Decodes `%XX` escapes in a query parameter value
+///
+/// Malformed escapes are kept as written.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Detects signatures in signed URLs and cookies
+///
+/// Covers:
+/// - GCS V4 signed URLs (X-Goog-Signature alongside X-Goog-Credential)
+/// - CloudFront signed URLs (Signature alongside Key-Pair-Id)
+/// - CloudFront signed cookies (CloudFront-Signature near CloudFront-Key-Pair-Id)
+///
+/// Percent-encoded signatures are decoded for the reported value; the span points
+/// at the signature as written.
+///
+/// # Arguments
+/// * `content` - The string to check for signed URLs
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_signed_urls(content: &str) -> Vec<SecretMatch> {
+    let mut secrets: Vec<SecretMatch> = Vec::new();
+
+    for captures in GCS_SIGNATURE_PATTERN.captures_iter(content) {
+        if let Some(signature) = captures.get(1) {
+            let url = enclosing_url(content, signature.start(), signature.end());
+            if url.contains("X-Goog-Credential=") {
+                secrets.push(SecretMatch::from_match(
+                    SecretType::GcsSignedUrlSignature,
+                    signature,
+                ));
+            }
+        }
+    }
+
+    for captures in CLOUDFRONT_URL_SIGNATURE_PATTERN.captures_iter(content) {
+        if let Some(signature) = captures.get(1) {
+            let url = enclosing_url(content, signature.start(), signature.end());
+            if url.contains("Key-Pair-Id=") {
+                secrets.push(SecretMatch::new(
+                    SecretType::CloudFrontSignedUrlSignature,
+                    percent_decode(signature.as_str()),
+                    signature.start(),
+                    signature.end(),
+                ));
+            }
+        }
+    }
+
+    for captures in CLOUDFRONT_COOKIE_SIGNATURE_PATTERN.captures_iter(content) {
+        if let Some(signature) = captures.get(1) {
+            let window = context_window(
+                content,
+                signature.start(),
+                signature.end(),
+                COOKIE_CONTEXT_WINDOW,
+            );
+            if window.contains("CloudFront-Key-Pair-Id=") {
+                secrets.push(SecretMatch::new(
+                    SecretType::CloudFrontSignedCookieSignature,
+                    percent_decode(signature.as_str()),
+                    signature.start(),
+                    signature.end(),
+                ));
+            }
+        }
+    }
+
+    secrets
+}
+
+/// Detector registered as `"signed_urls"`; see [`detect_signed_urls`]
+pub struct SignedUrlDetector;
+
+impl Detector for SignedUrlDetector {
+    fn name(&self) -> &str {
+        "signed_urls"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_signed_urls(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"Signature=".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GCS_SIGNATURE: &str = "1f0a9c3b7d2e4f6a8b0c1d2e3f4a5b6c7d8e9f0a1b2c3d4e5f6a7b8c9d0e1f2a";
+
+    #[test]
+    fn test_gcs_signed_url() {
+        let url = format!(
+            "curl \"https://storage.googleapis.com/acme-exports/report.csv?X-Goog-Algorithm=GOOG4-RSA-SHA256&X-Goog-Credential=exporter%40acme-prod.iam.gserviceaccount.com%2F20261016%2Fauto%2Fstorage%2Fgoog4_request&X-Goog-Date=20261016T120000Z&X-Goog-Expires=604800&X-Goog-SignedHeaders=host&X-Goog-Signature={GCS_SIGNATURE}\""
+        );
+        let result = detect_signed_urls(&url);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "GCS Signed URL Signature");
+        assert_eq!(result[0].value, GCS_SIGNATURE);
+        assert_eq!(&url[result[0].start..result[0].end], GCS_SIGNATURE);
+    }
+
+    #[test]
+    fn test_gcs_signature_requires_credential_in_same_url() {
+        let content = format!(
+            "https://storage.googleapis.com/a?X-Goog-Credential=svc\nhttps://example.com/b?X-Goog-Signature={GCS_SIGNATURE}"
+        );
+        assert!(detect_signed_urls(&content).is_empty());
+    }
+
+    #[test]
+    fn test_cloudfront_signed_url_decodes_signature() {
+        let url = "https://d111111abcdef8.cloudfront.net/video.mp4?Expires=1924992000&Signature=nitfHRCrtziwO2HwPfWw~yYDhUF5EwRunQA-j19DzZrvDh6hQ73lDx~-ar3UocvvRQVw6EkC~GdpGQyyOSKQim-TxAnW7d8F5Kkai9HVx0FIu%2BgHvqLJOOhpGF2yVxd%2Fm2rpz2AbBe8CJmfn8o%3D%3D&Key-Pair-Id=K2JCJMDEHXQW5F";
+        let result = detect_signed_urls(url);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "CloudFront Signed URL Signature");
+        assert_eq!(
+            result[0].value,
+            "nitfHRCrtziwO2HwPfWw~yYDhUF5EwRunQA-j19DzZrvDh6hQ73lDx~-ar3UocvvRQVw6EkC~GdpGQyyOSKQim-TxAnW7d8F5Kkai9HVx0FIu+gHvqLJOOhpGF2yVxd/m2rpz2AbBe8CJmfn8o=="
+        );
+        assert!(url[result[0].start..result[0].end].ends_with("%3D%3D"));
+    }
+
+    #[test]
+    fn test_cloudfront_signed_cookie_headers() {
+        let headers = "HTTP/1.1 200 OK\r\nSet-Cookie: CloudFront-Policy=eyJTdGF0ZW1lbnQiOlt7IlJlc291cmNlIjoiaHR0cHM6Ly9kMTExMTExYWJjZGVmOC5jbG91ZGZyb250Lm5ldC8qIn1dfQ__; Domain=d111111abcdef8.cloudfront.net; Path=/; Secure; HttpOnly\r\nSet-Cookie: CloudFront-Signature=dtKhpJ3aUYxqDIwepczPiDb9NXQ~Y1ww4A5RpNdI5I3m0sBJxq3VdTPzzWUdh1f1cZbDKR~pVZkjN0AwC7wZNm-7k2ZkFl~J4e2pi-rv8R5sL2k~Zq9GmY; Domain=d111111abcdef8.cloudfront.net; Path=/; Secure; HttpOnly\r\nSet-Cookie: CloudFront-Key-Pair-Id=K2JCJMDEHXQW5F; Domain=d111111abcdef8.cloudfront.net; Path=/; Secure; HttpOnly\r\n";
+        let result = detect_signed_urls(headers);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "CloudFront Signed Cookie Signature");
+        assert!(result[0].value.starts_with("dtKhpJ3aUYxqDIwepczPiDb9NXQ~"));
+        assert!(result[0].value.ends_with("Zq9GmY"));
+    }
+
+    #[test]
+    fn test_signature_without_key_pair_id_is_ignored() {
+        let url = "https://example.com/callback?Signature=nitfHRCrtziwO2HwPfWw~yYDhUF5EwRunQA-j19DzZrvDh6hQ73lDx";
+        assert!(detect_signed_urls(url).is_empty());
+    }
+
+    #[test]
+    fn test_percent_decode_keeps_malformed_escapes() {
+        assert_eq!(percent_decode("a%2Bb%3D"), "a+b=");
+        assert_eq!(percent_decode("a%zzb%4"), "a%zzb%4");
+    }
+}
@@ -0,0 +1,129 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::detector::Detector;
+use super::prescreen::{Anchor, Reach};
+use super::secret_match::{Confidence, Liveness, SecretMatch};
+use super::secret_type::SecretType;
+
+/// Prefix older sandbox credentials carry in front of their `sq0` prefix
+const SANDBOX_PREFIX: &str = "sandbox-";
+
+/// Regex patterns for Square credentials, with the type each reports
+/// Matches access tokens (sq0atp production, sq0atb sandbox) and OAuth application
+/// secrets (sq0csp production, sq0csb sandbox)
+static SQUARE_PATTERNS: Lazy<Vec<(Regex, SecretType)>> = Lazy::new(|| {
+    vec![
+        (
+            Regex::new(r"(?:sandbox-)?sq0at[pb]-[0-9A-Za-z_\-]{22}")
+                .expect("Invalid regex pattern"),
+            SecretType::SquareAccessToken,
+        ),
+        (
+            Regex::new(r"(?:sandbox-)?sq0cs[pb]-[0-9A-Za-z_\-]{43}")
+                .expect("Invalid regex pattern"),
+            SecretType::SquareOAuthSecret,
+        ),
+    ]
+});
+
+/// Detects all Square access tokens and OAuth secrets in a string
+///
+/// Sandbox credentials (`sq0atb-`, `sq0csb-`) are reported with [`Liveness::Test`].
+///
+/// # Arguments
+/// * `secret` - The string to check for Square credential patterns
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_square_tokens(secret: &str) -> Vec<SecretMatch> {
+    let mut tokens = Vec::new();
+
+    for (pattern, secret_type) in SQUARE_PATTERNS.iter() {
+        for token_match in pattern.find_iter(secret) {
+            let token = token_match.as_str();
+            // The letter before the dash is `p` for production, `b` for sandbox
+            let kind = token.trim_start_matches(SANDBOX_PREFIX).as_bytes()[5];
+            let liveness = if kind == b'b' {
+                Liveness::Test
+            } else {
+                Liveness::Live
+            };
+            tokens.push(
+                SecretMatch::from_match(secret_type.clone(), token_match).with_liveness(liveness),
+            );
+        }
+    }
+
+    tokens.sort_by_key(|token| token.start);
+    tokens
+}
+
+/// Detector registered as `"square"`; see [`detect_square_tokens`]
+pub struct SquareDetector;
+
+impl Detector for SquareDetector {
+    fn name(&self) -> &str {
+        "square"
+    }
+
+    fn confidence(&self) -> Confidence {
+        Confidence::High
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_square_tokens(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"sq0(?:at|cs)[pb]-".to_string())
+    }
+
+    fn anchor(&self) -> Option<Anchor> {
+        Some(Anchor::new(
+            &["sq0atp-", "sq0atb-", "sq0csp-", "sq0csb-"],
+            Reach::Bytes(SANDBOX_PREFIX.len()),
+            Reach::Bytes("sq0csp-".len() + 43),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCESS_TOKEN: &str = "sq0atp-aBcDeFgHiJkLmNoPqRsTu_";
+    const OAUTH_SECRET: &str = "sq0csp-aBcDeFgHiJkLmNoPqRsTuVwXyZ0123456789-_aBcDe";
+
+    #[test]
+    fn test_production_credentials() {
+        let content = format!("SQUARE_TOKEN={ACCESS_TOKEN}\nSQUARE_SECRET={OAUTH_SECRET}\n");
+        let result = detect_square_tokens(&content);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].secret_type, "Square Access Token");
+        assert_eq!(result[0].value, ACCESS_TOKEN);
+        assert_eq!(result[1].secret_type, "Square OAuth Secret");
+        assert_eq!(result[1].value, OAUTH_SECRET);
+        assert!(result.iter().all(|found| found.liveness == Liveness::Live));
+    }
+
+    #[test]
+    fn test_sandbox_credentials() {
+        for token in [
+            "sq0atb-aBcDeFgHiJkLmNoPqRsTu_",
+            "sandbox-sq0atb-aBcDeFgHiJkLmNoPqRsTu_",
+            "sandbox-sq0csb-aBcDeFgHiJkLmNoPqRsTuVwXyZ0123456789-_aBcDe",
+        ] {
+            let result = detect_square_tokens(&format!("token: {token}"));
+            assert_eq!(result.len(), 1, "{token}");
+            assert_eq!(result[0].value, token);
+            assert_eq!(result[0].liveness, Liveness::Test);
+        }
+    }
+
+    #[test]
+    fn test_invalid_square_tokens() {
+        assert!(detect_square_tokens("sq0atx-aBcDeFgHiJkLmNoPqRsTu_").is_empty());
+        assert!(detect_square_tokens("sq0atp-aBcDeFgHiJkLmNoPqRsT").is_empty());
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::detector::Detector;
+use super::secret_match::{Confidence, Liveness, SecretMatch};
+use super::secret_type::SecretType;
+
+/// Longest token body an override may allow, to keep widened patterns cheap
+const MAX_OVERRIDE_LEN: usize = 1024;
+
+/// Characters an override may never add, since they would let a token run into
+/// surrounding quotes, whitespace or escapes
+const FORBIDDEN_EXTRA_CHARS: &[char] = &['"', '\'', '`', '\\'];
+
+/// Widened patterns already compiled, keyed by pattern text
+static WIDENED_PATTERNS: Lazy<Mutex<HashMap<String, Regex>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Tolerances for a vendor token whose charset or length changed after a release
+///
+/// Unset lengths keep the rule's built-in bounds. `extra_chars` are added to the
+/// token body's charset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleOverride {
+    pub min_len: Option<usize>,
+    pub max_len: Option<usize>,
+    pub extra_chars: String,
+}
+
+/// The override-safe part of a built-in token pattern
+///
+/// A detector that declares one is matched by `{prefix}[{charset}]{min_len,max_len}{suffix}`,
+/// and only the charset and length of the body can be overridden. Prefixes and
+/// suffixes stay fixed.
+pub struct TokenRule {
+    /// Regex for the fixed vendor prefix
+    pub prefix: &'static str,
+    /// Contents of the character class for the token body
+    pub charset: &'static str,
+    pub min_len: usize,
+    pub max_len: usize,
+    /// Regex that must follow the body, e.g. `\b`
+    pub suffix: &'static str,
+}
+
+impl TokenRule {
+    /// Returns the stock pattern for this rule
+    pub fn pattern(&self) -> String {
+        Self::build_pattern(
+            self.prefix,
+            self.charset,
+            self.min_len,
+            self.max_len,
+            self.suffix,
+        )
+    }
+
+    fn build_pattern(
+        prefix: &str,
+        charset: &str,
+        min_len: usize,
+        max_len: usize,
+        suffix: &str,
+    ) -> String {
+        let quantifier = if min_len == max_len {
+            format!("{{{min_len}}}")
+        } else {
+            format!("{{{min_len},{max_len}}}")
+        };
+        format!("{prefix}[{charset}]{quantifier}{suffix}")
+    }
+
+    /// Compiles this rule with an override applied
+    ///
+    /// # Arguments
+    /// * `rule` - Lengths and extra characters to apply to the token body
+    ///
+    /// # Returns
+    /// * `Result<Regex, String>` - The widened pattern, or why the override was rejected
+    pub fn widen(&self, rule: &RuleOverride) -> Result<Regex, String> {
+        let min_len = rule.min_len.unwrap_or(self.min_len);
+        let max_len = rule.max_len.unwrap_or(self.max_len);
+        if min_len == 0 {
+            return Err("min_len must be at least 1".to_string());
+        }
+        if min_len > max_len {
+            return Err(format!(
+                "min_len {min_len} is greater than max_len {max_len}"
+            ));
+        }
+        if max_len > MAX_OVERRIDE_LEN {
+            return Err(format!(
+                "max_len {max_len} is over the limit of {MAX_OVERRIDE_LEN}"
+            ));
+        }
+
+        let mut charset = self.charset.to_string();
+        for c in rule.extra_chars.chars() {
+            if !c.is_ascii_graphic() || FORBIDDEN_EXTRA_CHARS.contains(&c) {
+                return Err(format!(
+                    "extra_chars may only add printable ASCII other than quotes and backslashes, got {c:?}"
+                ));
+            }
+            if c.is_ascii_punctuation() {
+                charset.push('\\');
+            }
+            charset.push(c);
+        }
+
+        let pattern = Self::build_pattern(self.prefix, &charset, min_len, max_len, self.suffix);
+        let mut cache = WIDENED_PATTERNS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(regex) = cache.get(&pattern) {
+            return Ok(regex.clone());
+        }
+        let regex = Regex::new(&pattern).map_err(|err| err.to_string())?;
+        cache.insert(pattern, regex.clone());
+        Ok(regex)
+    }
+}
+
+/// A built-in token detector running a widened pattern in place of its stock one
+pub struct WidenedDetector {
+    name: &'static str,
+    secret_type: SecretType,
+    pattern: Regex,
+    /// Tells live tokens from test ones, for vendors that issue both
+    liveness: fn(&str) -> Liveness,
+}
+
+impl WidenedDetector {
+    pub fn new(name: &'static str, secret_type: SecretType, pattern: Regex) -> Self {
+        WidenedDetector {
+            name,
+            secret_type,
+            pattern,
+            liveness: |_| Liveness::Unknown,
+        }
+    }
+
+    /// Classifies each match the way the stock detector does
+    pub fn liveness(mut self, liveness: fn(&str) -> Liveness) -> Self {
+        self.liveness = liveness;
+        self
+    }
+}
+
+impl Detector for WidenedDetector {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        self.pattern
+            .find_iter(content)
+            .map(|found| {
+                SecretMatch::from_match(self.secret_type.clone(), found)
+                    .with_liveness((self.liveness)(found.as_str()))
+            })
+            .collect()
+    }
+
+    fn confidence(&self) -> Confidence {
+        self.secret_type.confidence()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULE: TokenRule = TokenRule {
+        prefix: "tok_",
+        charset: "a-z",
+        min_len: 8,
+        max_len: 8,
+        suffix: r"\b",
+    };
+
+    #[test]
+    fn test_stock_pattern() {
+        assert_eq!(RULE.pattern(), r"tok_[a-z]{8}\b");
+    }
+
+    #[test]
+    fn test_widen_lengths_and_charset() {
+        let widened = RULE
+            .widen(&RuleOverride {
+                max_len: Some(12),
+                extra_chars: "-.".to_string(),
+                ..RuleOverride::default()
+            })
+            .unwrap();
+        assert_eq!(widened.as_str(), r"tok_[a-z\-\.]{8,12}\b");
+        assert!(widened.is_match("tok_abc-def.gh"));
+        assert!(!widened.is_match("tok_abc/defgh"));
+    }
+
+    #[test]
+    fn test_nonsensical_overrides_are_rejected() {
+        let invalid = [
+            RuleOverride {
+                min_len: Some(0),
+                ..RuleOverride::default()
+            },
+            RuleOverride {
+                min_len: Some(10),
+                ..RuleOverride::default()
+            },
+            RuleOverride {
+                max_len: Some(100_000),
+                ..RuleOverride::default()
+            },
+            RuleOverride {
+                extra_chars: "\"".to_string(),
+                ..RuleOverride::default()
+            },
+            RuleOverride {
+                extra_chars: " ".to_string(),
+                ..RuleOverride::default()
+            },
+        ];
+        for rule in &invalid {
+            assert!(RULE.widen(rule).is_err(), "{rule:?} was accepted");
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::detector::Detector;
+use super::secret_match::SecretMatch;
+use super::secret_type::SecretType;
+
+/// Regex pattern for otpauth:// provisioning URIs
+/// Pattern captures: (1) the query string
+static OTPAUTH_URI_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)otpauth://(?:totp|hotp)/[^?\s'"<>]*\?([^\s'"<>]+)"#)
+        .expect("Invalid regex pattern")
+});
+
+/// Regex pattern for values assigned to TOTP/MFA seed keywords
+/// Pattern captures: (1) the assigned value, validated as base32 afterwards
+static TOTP_CONTEXT_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)(?:totp|2fa|mfa_secret|otp_secret)[A-Za-z0-9_-]*['"]?\s*[=:]\s*['"]?([A-Za-z0-9=]+)"#,
+    )
+    .expect("Invalid regex pattern")
+});
+
+/// Lengths (without padding) of bare base32 seeds accepted next to a keyword
+/// 16, 26 and 32 characters encode the common 80, 128 and 160 bit seeds
+const CONTEXT_SEED_LENGTHS: &[usize] = &[16, 26, 32];
+
+/// Minimum length (without padding) of a seed taken from an otpauth URI
+const MIN_URI_SEED_LENGTH: usize = 16;
+
+/// Checks whether a value is base32, ignoring case and trailing `=` padding
+///
+/// # Arguments
+/// * `value` - The candidate seed
+///
+/// # Returns
+/// * `Option<usize>` - The unpadded length if the value is valid base32
+fn base32_seed_len(value: &str) -> Option<usize> {
+    let unpadded = value.trim_end_matches('=');
+    if unpadded.is_empty()
+        || !unpadded
+            .bytes()
+            .all(|b| matches!(b.to_ascii_uppercase(), b'A'..=b'Z' | b'2'..=b'7'))
+    {
+        return None;
+    }
+    Some(unpadded.len())
+}
+
+/// Detects TOTP seeds and MFA secrets
+///
+/// Covers:
+/// - The `secret` parameter of otpauth://totp and otpauth://hotp URIs
+/// - Bare base32 seeds of 16, 26 or 32 characters assigned to `totp`, `2fa`,
+///   `mfa_secret` or `otp_secret` keys
+///
+/// Lowercase seeds and seeds with `=` padding are accepted.
+///
+/// # Arguments
+/// * `content` - The string to check for TOTP seeds
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_totp_secrets(content: &str) -> Vec<SecretMatch> {
+    let mut secrets: Vec<SecretMatch> = Vec::new();
+
+    for captures in OTPAUTH_URI_PATTERN.captures_iter(content) {
+        let Some(query) = captures.get(1) else {
+            continue;
+        };
+        for param in query.as_str().split('&') {
+            let Some(seed) = param.strip_prefix("secret=") else {
+                continue;
+            };
+            if base32_seed_len(seed).is_some_and(|len| len >= MIN_URI_SEED_LENGTH) {
+                secrets.push(SecretMatch::from_slice(
+                    SecretType::TotpSecret,
+                    content,
+                    seed,
+                ));
+            }
+        }
+    }
+
+    for captures in TOTP_CONTEXT_PATTERN.captures_iter(content) {
+        let Some(seed) = captures.get(1) else {
+            continue;
+        };
+        if !base32_seed_len(seed.as_str()).is_some_and(|len| CONTEXT_SEED_LENGTHS.contains(&len)) {
+            continue;
+        }
+        if !secrets
+            .iter()
+            .any(|other| other.start < seed.end() && seed.start() < other.end)
+        {
+            secrets.push(SecretMatch::from_match(SecretType::TotpSecret, seed));
+        }
+    }
+
+    secrets
+}
+
+/// Detector registered as `"totp"`; see [`detect_totp_secrets`]
+pub struct TotpDetector;
+
+impl Detector for TotpDetector {
+    fn name(&self) -> &str {
+        "totp"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_totp_secrets(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"(?i)otpauth://|totp|2fa|mfa_secret|otp_secret".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_otpauth_uri_in_provisioning_script() {
+        let script = r#"#!/bin/sh
+qrencode -o admin.png "otpauth://totp/ACME:admin@example.com?secret=JBSWY3DPEHPK3PXP&issuer=ACME&digits=6"
+"#;
+        let result = detect_totp_secrets(script);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "TOTP Secret");
+        assert_eq!(result[0].value, "JBSWY3DPEHPK3PXP");
+    }
+
+    #[test]
+    fn test_otpauth_uri_lowercase_and_padded() {
+        let uri = "otpauth://totp/Example?issuer=Example&secret=jbswy3dpehpk3pxpjbswy3dpeh======";
+        let result = detect_totp_secrets(uri);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].value, "jbswy3dpehpk3pxpjbswy3dpeh======");
+    }
+
+    #[test]
+    fn test_otpauth_uri_with_invalid_secret() {
+        // 0, 1, 8 and 9 are not in the base32 alphabet
+        assert!(detect_totp_secrets("otpauth://totp/X?secret=0189018901890189").is_empty());
+        assert!(detect_totp_secrets("otpauth://totp/X?secret=JBSWY3DP").is_empty());
+    }
+
+    #[test]
+    fn test_yaml_mfa_secret() {
+        let yaml =
+            "users:\n  - name: deploy\n    mfa_secret: \"GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ\"\n";
+        let result = detect_totp_secrets(yaml);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "TOTP Secret");
+        assert_eq!(result[0].value, "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ");
+    }
+
+    #[test]
+    fn test_context_keywords() {
+        assert_eq!(detect_totp_secrets("TOTP_SEED=JBSWY3DPEHPK3PXP").len(), 1);
+        assert_eq!(
+            detect_totp_secrets("user_2fa_key: jbswy3dpehpk3pxp").len(),
+            1
+        );
+        assert_eq!(
+            detect_totp_secrets("otp_secret = 'JBSWY3DPEHPK3PXPJBSWY3DPEH'").len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_base32_word_without_context_not_reported() {
+        assert!(detect_totp_secrets("const LABEL = \"ABCDEFGHIJKLMNOP\";").is_empty());
+        assert!(detect_totp_secrets("JBSWY3DPEHPK3PXP").is_empty());
+    }
+
+    #[test]
+    fn test_context_seed_with_unexpected_length_not_reported() {
+        assert!(detect_totp_secrets("mfa_secret: JBSWY3DPEHPK3PXPAB").is_empty());
+    }
+}
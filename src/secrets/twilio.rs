@@ -1,6 +1,11 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
+use super::context::{ContextRule, PLACEHOLDER_KEYWORDS};
+use super::detector::Detector;
+use super::secret_match::{Liveness, SecretMatch};
+use super::secret_type::SecretType;
+
 /// Regex patterns for Twilio API key detection
 static TWILIO_KEY_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| {
     vec![
@@ -9,23 +14,76 @@ static TWILIO_KEY_PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| {
     ]
 });
 
-/// Detects all Twilio API keys in a string
+/// Context rule for Twilio auth tokens
+/// Auth tokens are 32 lowercase hex characters with no prefix, so "twilio" must appear nearby
+static TWILIO_AUTH_TOKEN_RULE: Lazy<ContextRule> = Lazy::new(|| {
+    ContextRule::new(
+        SecretType::TwilioAuthToken,
+        r"\b([0-9a-f]{32})\b",
+        &["twilio"],
+    )
+    .expect("Invalid regex pattern")
+    .forbidden(PLACEHOLDER_KEYWORDS)
+});
+
+/// A `test` word in the text before a credential, as in `TWILIO_TEST_AUTH_TOKEN=`
+static TEST_CREDENTIAL_NAME: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)(?:^|[^a-z])test(?:[^a-z]|$)").expect("Invalid regex pattern"));
+
+/// Marks a match as a test credential when its line names it as one
+///
+/// Twilio's test credentials have the same format as live ones, so only the name they
+/// are assigned to tells them apart. Everything else stays [`Liveness::Unknown`].
+fn with_test_liveness(content: &str, found: SecretMatch) -> SecretMatch {
+    let line_start = content[..found.start].rfind('\n').map_or(0, |at| at + 1);
+    if TEST_CREDENTIAL_NAME.is_match(&content[line_start..found.start]) {
+        found.with_liveness(Liveness::Test)
+    } else {
+        found
+    }
+}
+
+/// Detects all Twilio API keys and auth tokens in a string
+///
+/// Credentials assigned to a name with a `test` word, like `TWILIO_TEST_ACCOUNT_SID`,
+/// are reported with [`Liveness::Test`].
 ///
 /// # Arguments
 /// * `secret` - The string to check for Twilio API key patterns
 ///
 /// # Returns
-/// * `Vec<(String, String)>` - List of all (secret_type, value) pairs found
-pub fn detect_twilio_keys(secret: &str) -> Vec<(String, String)> {
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_twilio_keys(secret: &str) -> Vec<SecretMatch> {
     let mut keys = Vec::new();
 
     for pattern in TWILIO_KEY_PATTERNS.iter() {
         for key_match in pattern.find_iter(secret) {
-            keys.push(("Twilio API Key".to_string(), key_match.as_str().to_string()));
+            keys.push(SecretMatch::from_match(SecretType::TwilioApiKey, key_match));
         }
     }
 
-    keys
+    keys.extend(TWILIO_AUTH_TOKEN_RULE.find_matches(secret));
+
+    keys.into_iter()
+        .map(|found| with_test_liveness(secret, found))
+        .collect()
+}
+
+/// Detector registered as `"twilio"`; see [`detect_twilio_keys`]
+pub struct TwilioDetector;
+
+impl Detector for TwilioDetector {
+    fn name(&self) -> &str {
+        "twilio"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_twilio_keys(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"(?:AC|SK)[a-z0-9]{32}|(?i:twilio)".to_string())
+    }
 }
 
 #[cfg(test)]
@@ -37,9 +95,9 @@ mod tests {
         let key = format!("AC{}", "a".repeat(32));
         let result = detect_twilio_keys(&key);
         assert!(!result.is_empty());
-        let (secret_type, value) = result.first().unwrap();
+        let (secret_type, value) = result[0].clone().into_pair();
         assert_eq!(secret_type, "Twilio API Key");
-        assert_eq!(value, &key);
+        assert_eq!(value, key);
     }
 
     #[test]
@@ -47,7 +105,7 @@ mod tests {
         let key = format!("SK{}", "1".repeat(32));
         let result = detect_twilio_keys(&key);
         assert!(!result.is_empty());
-        assert_eq!(result.first().unwrap().1, key);
+        assert_eq!(result.first().unwrap().value, key);
     }
 
     #[test]
@@ -56,7 +114,7 @@ mod tests {
         let code = format!("TWILIO_KEY = '{key}'");
         let result = detect_twilio_keys(&code);
         assert!(!result.is_empty());
-        assert_eq!(result.first().unwrap().1, key);
+        assert_eq!(result.first().unwrap().value, key);
     }
 
     #[test]
@@ -85,4 +143,46 @@ mod tests {
         let key = format!("AC{}", "A".repeat(32));
         assert!(detect_twilio_keys(&key).is_empty());
     }
+
+    #[test]
+    fn test_twilio_auth_token_in_env_file() {
+        let env = format!(
+            "TWILIO_ACCOUNT_SID=AC{}\nTWILIO_AUTH_TOKEN=9f86d081884c7d659a2feaa0c55ad015\n",
+            "a".repeat(32)
+        );
+        let result = detect_twilio_keys(&env);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].secret_type, "Twilio Auth Token");
+        assert_eq!(result[1].value, "9f86d081884c7d659a2feaa0c55ad015");
+    }
+
+    #[test]
+    fn test_test_credentials_by_name() {
+        let env = format!(
+            "TWILIO_TEST_ACCOUNT_SID=AC{sid}\nTWILIO_TEST_AUTH_TOKEN=9f86d081884c7d659a2feaa0c55ad015\nTWILIO_ACCOUNT_SID=AC{sid}\nLATEST_TWILIO_SID=AC{sid}\n",
+            sid = "a".repeat(32)
+        );
+        let liveness: Vec<Liveness> = detect_twilio_keys(&env)
+            .iter()
+            .map(|found| found.liveness)
+            .collect();
+        assert_eq!(
+            liveness,
+            vec![
+                Liveness::Test,
+                Liveness::Unknown,
+                Liveness::Unknown,
+                Liveness::Test
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hex_without_twilio_context_not_auth_token() {
+        assert!(detect_twilio_keys("md5: 9f86d081884c7d659a2feaa0c55ad015").is_empty());
+        assert!(
+            detect_twilio_keys("TWILIO_AUTH_TOKEN=9f86d081884c7d659a2feaa0c55ad015 # example")
+                .is_empty()
+        );
+    }
 }
@@ -0,0 +1,377 @@
+use des::cipher::{generic_array::GenericArray, BlockDecrypt, KeyInit};
+use des::Des;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::detector::Detector;
+use super::secret_match::{slice_offset, SecretMatch};
+use super::secret_type::SecretType;
+
+/// Fixed DES key VNC servers use to obfuscate stored passwords
+/// This is the well-known key {23, 82, 107, 6, 35, 78, 88, 7} with each byte's bits
+/// reversed, as VNC's d3des expects, so it can be used with a standard DES implementation.
+const VNC_DES_KEY: [u8; 8] = [0xE8, 0x4A, 0xD6, 0x60, 0xC4, 0x72, 0x1A, 0xE0];
+
+/// Registry value names under VNC server keys that hold DES-obfuscated passwords
+static VNC_PASSWORD_VALUES: &[&str] = &["Password", "PasswordViewOnly", "ControlPassword"];
+
+/// Regex pattern for .reg file key headers
+/// Pattern captures: (1) the key path
+static REG_KEY_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\[-?([^\]]+)\]\s*$").expect("Invalid regex pattern"));
+
+/// Regex pattern for .reg file named values
+/// Pattern captures: (1) the value name, (2) the raw value data
+static REG_VALUE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^"((?:[^"\\]|\\.)*)"\s*=\s*(.*)$"#).expect("Invalid regex pattern"));
+
+/// Regex pattern for the password logon type in Task Scheduler XML
+static TASK_PASSWORD_LOGON_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"<LogonType>\s*Password\s*</LogonType>").expect("Invalid regex pattern")
+});
+
+/// Regex pattern for password elements in Task Scheduler XML
+/// Pattern captures: (1) the password
+static TASK_PASSWORD_ELEMENT_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"<Password>([^<]+)</Password>").expect("Invalid regex pattern"));
+
+/// Regex pattern for Group Policy Preferences cpassword attributes
+/// These are AES-encrypted with a key Microsoft published, so they are reversible
+/// Pattern captures: (1) the base64 cpassword
+static GPP_CPASSWORD_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\bcpassword="([A-Za-z0-9+/=]{16,})""#).expect("Invalid regex pattern")
+});
+
+/// Data of a registry value in a .reg export
+#[derive(Debug, PartialEq)]
+enum RegData {
+    /// REG_SZ string, or REG_EXPAND_SZ decoded from UTF-16 hex
+    String(String),
+    /// REG_BINARY bytes
+    Binary(Vec<u8>),
+    /// Any other value type
+    Other,
+}
+
+/// A named value in a .reg export with the key it belongs to
+struct RegValue<'a> {
+    key: &'a str,
+    name: String,
+    data: RegData,
+    /// Byte span of the raw data in the export, inside the quotes of a string value
+    span: (usize, usize),
+}
+
+/// Parses comma-separated hex bytes such as `de,ad,be,ef`
+fn parse_hex_bytes(raw: &str) -> Option<Vec<u8>> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|byte| !byte.is_empty())
+        .map(|byte| u8::from_str_radix(byte, 16).ok())
+        .collect()
+}
+
+/// Decodes UTF-16LE bytes up to the first NUL character
+fn decode_utf16le(bytes: &[u8]) -> Option<String> {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .take_while(|unit| *unit != 0)
+        .collect();
+    String::from_utf16(&units).ok()
+}
+
+/// Resolves backslash escapes in a quoted .reg string
+fn unescape_reg_string(raw: &str) -> String {
+    let mut value = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(escaped) = chars.next() {
+                value.push(escaped);
+            }
+        } else {
+            value.push(c);
+        }
+    }
+    value
+}
+
+/// Parses the data part of a .reg value line
+fn parse_reg_data(raw: &str) -> RegData {
+    let raw = raw.trim();
+    if let Some(quoted) = raw.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        return RegData::String(unescape_reg_string(quoted));
+    }
+    if let Some(hex) = raw.strip_prefix("hex:") {
+        return parse_hex_bytes(hex).map_or(RegData::Other, RegData::Binary);
+    }
+    if let Some(hex) = raw.strip_prefix("hex(2):") {
+        return parse_hex_bytes(hex)
+            .and_then(|bytes| decode_utf16le(&bytes))
+            .map_or(RegData::Other, RegData::String);
+    }
+    RegData::Other
+}
+
+/// Parses the named values of a .reg export
+///
+/// Handles `\` line continuations used by long hex values. Default values (`@=`),
+/// comments and values outside a key are ignored.
+fn reg_values(content: &str) -> Vec<RegValue<'_>> {
+    let mut values = Vec::new();
+    let mut key: Option<&str> = None;
+    let mut lines = content.lines();
+
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if let Some(captures) = REG_KEY_PATTERN.captures(line) {
+            key = captures.get(1).map(|path| path.as_str());
+            continue;
+        }
+        let Some(key) = key else {
+            continue;
+        };
+        let line_start = slice_offset(content, line);
+        let mut data_end = line_start + line.len();
+        let mut logical = line.to_string();
+        while logical.ends_with('\\') {
+            logical.pop();
+            match lines.next() {
+                Some(next) => {
+                    let next = next.trim();
+                    logical.push_str(next);
+                    data_end = slice_offset(content, next) + next.len();
+                }
+                None => break,
+            }
+        }
+        let Some(captures) = REG_VALUE_PATTERN.captures(&logical) else {
+            continue;
+        };
+        let (Some(name), Some(data)) = (captures.get(1), captures.get(2)) else {
+            continue;
+        };
+        // The name and `=` are always on the first physical line
+        let mut span = (line_start + data.start().min(line.len()), data_end);
+        let raw = data.as_str().trim();
+        if raw.len() >= 2 && raw.starts_with('"') && raw.ends_with('"') {
+            span = (span.0 + 1, span.1 - 1);
+        }
+        values.push(RegValue {
+            key,
+            name: unescape_reg_string(name.as_str()),
+            data: parse_reg_data(raw),
+            span,
+        });
+    }
+
+    values
+}
+
+/// Decrypts a VNC password stored with the fixed VNC DES key
+///
+/// # Arguments
+/// * `encrypted` - The stored password bytes; only the first 8 bytes are used
+///
+/// # Returns
+/// * `Option<String>` - The plaintext password, or None if it is empty or not UTF-8
+fn decrypt_vnc_password(encrypted: &[u8]) -> Option<String> {
+    if encrypted.len() < 8 {
+        return None;
+    }
+    let cipher = Des::new(&GenericArray::from(VNC_DES_KEY));
+    let mut block = GenericArray::clone_from_slice(&encrypted[..8]);
+    cipher.decrypt_block(&mut block);
+    let plaintext: Vec<u8> = block.iter().copied().take_while(|b| *b != 0).collect();
+    String::from_utf8(plaintext)
+        .ok()
+        .filter(|password| !password.is_empty())
+}
+
+/// Detects credentials in Windows registry exports and Group Policy files
+///
+/// Covers:
+/// - Winlogon autologon `DefaultPassword` values in .reg exports, including
+///   `hex(2):` encoded values
+/// - VNC server `Password` values in .reg exports, which are DES-obfuscated with a
+///   fixed key; the decrypted plaintext is reported
+/// - `<Password>` elements in Task Scheduler XML using the Password logon type
+/// - Group Policy Preferences `cpassword` attributes, which use a published AES key
+///
+/// # Arguments
+/// * `content` - The string to check for Windows registry and GPO credentials
+///
+/// # Returns
+/// * `Vec<SecretMatch>` - List of all matches found, with byte offsets into the input
+pub fn detect_windows_registry_secrets(content: &str) -> Vec<SecretMatch> {
+    let mut secrets = Vec::new();
+
+    for value in reg_values(content) {
+        let key = value.key.to_ascii_lowercase();
+        match value.data {
+            RegData::String(password)
+                if key.ends_with("\\winlogon")
+                    && value.name.eq_ignore_ascii_case("DefaultPassword")
+                    && !password.is_empty() =>
+            {
+                let (start, end) = value.span;
+                secrets.push(SecretMatch::new(
+                    SecretType::WindowsAutologonPassword,
+                    password,
+                    start,
+                    end,
+                ));
+            }
+            RegData::Binary(bytes)
+                if key.contains("vnc")
+                    && VNC_PASSWORD_VALUES
+                        .iter()
+                        .any(|name| value.name.eq_ignore_ascii_case(name)) =>
+            {
+                if let Some(password) = decrypt_vnc_password(&bytes) {
+                    let (start, end) = value.span;
+                    secrets.push(SecretMatch::new(
+                        SecretType::VncPassword,
+                        password,
+                        start,
+                        end,
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if TASK_PASSWORD_LOGON_PATTERN.is_match(content) {
+        for captures in TASK_PASSWORD_ELEMENT_PATTERN.captures_iter(content) {
+            if let Some(password) = captures.get(1) {
+                secrets.push(SecretMatch::from_match(
+                    SecretType::ScheduledTaskPassword,
+                    password,
+                ));
+            }
+        }
+    }
+
+    for captures in GPP_CPASSWORD_PATTERN.captures_iter(content) {
+        if let Some(cpassword) = captures.get(1) {
+            secrets.push(SecretMatch::from_match(
+                SecretType::GroupPolicyPreferencesPassword,
+                cpassword,
+            ));
+        }
+    }
+
+    secrets
+}
+
+/// Detector registered as `"windows_registry"`; see [`detect_windows_registry_secrets`]
+pub struct WindowsRegistryDetector;
+
+impl Detector for WindowsRegistryDetector {
+    fn name(&self) -> &str {
+        "windows_registry"
+    }
+
+    fn detect(&self, content: &str) -> Vec<SecretMatch> {
+        detect_windows_registry_secrets(content)
+    }
+
+    fn prescreen(&self) -> Option<String> {
+        Some(r"(?i:vnc|winlogon)|<Password>|cpassword=".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_autologon_reg_export() {
+        let reg = r#"Windows Registry Editor Version 5.00
+
+[HKEY_LOCAL_MACHINE\SOFTWARE\Microsoft\Windows NT\CurrentVersion\Winlogon]
+"AutoAdminLogon"="1"
+"DefaultUserName"="kiosk"
+"DefaultPassword"="K1osk\"Pass"
+"#;
+        let result = detect_windows_registry_secrets(reg);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Windows Autologon Password");
+        assert_eq!(result[0].value, "K1osk\"Pass");
+        // The span covers the escaped text inside the quotes
+        assert_eq!(&reg[result[0].start..result[0].end], r#"K1osk\"Pass"#);
+    }
+
+    #[test]
+    fn test_autologon_expand_sz_value() {
+        // "Pass1" as UTF-16LE with a terminating NUL, split over two lines
+        let reg = "[HKEY_LOCAL_MACHINE\\SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\\Winlogon]\n\"DefaultPassword\"=hex(2):50,00,61,00,73,00,\\\n  73,00,31,00,00,00\n";
+        let result = detect_windows_registry_secrets(reg);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].value, "Pass1");
+        // The span runs from the data on the first line to the end of the continuation
+        assert_eq!(
+            &reg[result[0].start..result[0].end],
+            "hex(2):50,00,61,00,73,00,\\\n  73,00,31,00,00,00"
+        );
+    }
+
+    #[test]
+    fn test_vnc_password_reg_export() {
+        let reg = r#"Windows Registry Editor Version 5.00
+
+[HKEY_LOCAL_MACHINE\SOFTWARE\TightVNC\Server]
+"Password"=hex:d7,a5,14,d8,c5,56,aa,de
+"RfbPort"=dword:0000170c
+"#;
+        let result = detect_windows_registry_secrets(reg);
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].secret_type,
+            "VNC Password (Reversible Encryption)"
+        );
+        assert_eq!(result[0].value, "Secure!");
+    }
+
+    #[test]
+    fn test_password_value_outside_known_keys_not_reported() {
+        let reg = "[HKEY_CURRENT_USER\\Software\\Example]\n\"DefaultPassword\"=\"notwinlogon\"\n\"Password\"=hex:01,02,03,04,05,06,07,08\n";
+        assert!(detect_windows_registry_secrets(reg).is_empty());
+    }
+
+    #[test]
+    fn test_scheduled_task_xml() {
+        let task = r#"<?xml version="1.0" encoding="UTF-16"?>
+<Task version="1.2" xmlns="http://schemas.microsoft.com/windows/2004/02/mit/task">
+  <Principals>
+    <Principal id="Author">
+      <UserId>CORP\svc_backup</UserId>
+      <LogonType>Password</LogonType>
+      <Password>Backup#Svc2024</Password>
+    </Principal>
+  </Principals>
+</Task>
+"#;
+        let result = detect_windows_registry_secrets(task);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].secret_type, "Scheduled Task Password");
+        assert_eq!(result[0].value, "Backup#Svc2024");
+    }
+
+    #[test]
+    fn test_gpp_scheduled_task_cpassword() {
+        let gpp = r#"<ScheduledTasks clsid="{CC63F200-7309-4ba0-B154-A71CD118DBCC}"><Task name="cleanup"><Properties runAs="CORP\svc_clean" cpassword="j1Uyj3Vx8TY9LtLZil2uAuZkFQA/4latT76ZwgdHdhw" logonType="Password"/></Task></ScheduledTasks>"#;
+        let result = detect_windows_registry_secrets(gpp);
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].secret_type,
+            "Group Policy Preferences Password (Reversible Encryption)"
+        );
+        assert_eq!(
+            result[0].value,
+            "j1Uyj3Vx8TY9LtLZil2uAuZkFQA/4latT76ZwgdHdhw"
+        );
+    }
+}
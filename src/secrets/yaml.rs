@@ -0,0 +1,128 @@
+/// A `key: value` line of a YAML document with the mapping keys leading to it
+pub struct YamlEntry<'a> {
+    /// Keys from the document root down to and including this entry's key
+    pub path: Vec<&'a str>,
+    /// The scalar value with surrounding quotes and trailing comments removed
+    pub value: &'a str,
+}
+
+impl YamlEntry<'_> {
+    /// Checks whether the entry's path ends with the given keys
+    ///
+    /// # Arguments
+    /// * `suffix` - Trailing keys to compare, e.g. `&["auth", "password"]`
+    ///
+    /// # Returns
+    /// * `bool` - true if the last keys of the path equal `suffix`
+    pub fn path_ends_with(&self, suffix: &[&str]) -> bool {
+        self.path.ends_with(suffix)
+    }
+}
+
+/// Strips quotes and a trailing comment from a YAML scalar
+fn clean_scalar(raw: &str) -> &str {
+    let raw = raw.trim();
+    for quote in ['"', '\''] {
+        if let Some(inner) = raw.strip_prefix(quote) {
+            return inner.split(quote).next().unwrap_or("");
+        }
+    }
+    raw.split(" #").next().unwrap_or("").trim_end()
+}
+
+/// Walks a block-style YAML document and returns its scalar entries with key paths
+///
+/// This is a line-based reader, not a full YAML parser: it tracks nesting by
+/// indentation, treats `- ` list markers as extra indentation, and ignores flow
+/// collections, anchors and multi-line scalars. That is enough to locate keys in
+/// config files like clouds.yaml or Talos machine configs.
+///
+/// # Arguments
+/// * `content` - The YAML document
+///
+/// # Returns
+/// * `Vec<YamlEntry>` - One entry per `key: value` line with a non-empty value
+pub fn yaml_scalar_entries(content: &str) -> Vec<YamlEntry<'_>> {
+    let mut stack: Vec<(usize, &str)> = Vec::new();
+    let mut entries = Vec::new();
+
+    for line in content.lines() {
+        let mut rest = line.trim_start();
+        let mut indent = line.len() - rest.len();
+        if rest.is_empty() || rest.starts_with('#') || rest.starts_with("---") {
+            continue;
+        }
+        while let Some(item) = rest.strip_prefix("- ") {
+            let item_trimmed = item.trim_start();
+            indent += rest.len() - item_trimmed.len();
+            rest = item_trimmed;
+        }
+        let Some((key, value)) = rest.split_once(':') else {
+            continue;
+        };
+        if !value.is_empty() && !value.starts_with(' ') {
+            // Not a mapping key, e.g. a URL list item
+            continue;
+        }
+        let key = key.trim().trim_matches(|c| c == '"' || c == '\'');
+
+        while stack.last().is_some_and(|(depth, _)| *depth >= indent) {
+            stack.pop();
+        }
+        stack.push((indent, key));
+
+        let value = clean_scalar(value);
+        if !value.is_empty() {
+            entries.push(YamlEntry {
+                path: stack.iter().map(|(_, key)| *key).collect(),
+                value,
+            });
+        }
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_paths() {
+        let yaml = "clouds:
+  prod:
+    auth:
+      username: admin # operator account
+      password: \"s3cret\"
+  dev:
+    region_name: 'RegionOne'
+";
+        let entries = yaml_scalar_entries(yaml);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].path, ["clouds", "prod", "auth", "username"]);
+        assert_eq!(entries[0].value, "admin");
+        assert_eq!(entries[1].path, ["clouds", "prod", "auth", "password"]);
+        assert_eq!(entries[1].value, "s3cret");
+        assert!(entries[1].path_ends_with(&["auth", "password"]));
+        assert_eq!(entries[2].path, ["clouds", "dev", "region_name"]);
+        assert_eq!(entries[2].value, "RegionOne");
+    }
+
+    #[test]
+    fn test_list_items() {
+        let yaml = "bootstrapTokens:
+- token: abcdef.0123456789abcdef
+  ttl: 24h0m0s
+";
+        let entries = yaml_scalar_entries(yaml);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].path, ["bootstrapTokens", "token"]);
+        assert_eq!(entries[1].path, ["bootstrapTokens", "ttl"]);
+    }
+
+    #[test]
+    fn test_non_mapping_lines_ignored() {
+        let entries = yaml_scalar_entries("urls:\n  - https://example.com\n# comment: x\n");
+        assert!(entries.is_empty());
+    }
+}
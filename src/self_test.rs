@@ -0,0 +1,288 @@
+//! Checking at startup that every enabled rule still works
+//!
+//! A build with different features, a WASM target or a regex crate with features
+//! stripped can break detectors without any error. [`Scanner::self_test`] runs each
+//! enabled rule over its samples from [`crate::ruleset_corpus`], or the sample given
+//! with a custom rule, and reports which rules missed a sample or matched a near miss.
+//! It takes a few milliseconds, so services can run it at boot and serve the
+//! [`SelfTestReport`] from a health endpoint.
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::ruleset::ruleset_corpus;
+use crate::scanner::Scanner;
+use crate::secrets::detector::Detector;
+
+/// Why one sample failed
+///
+/// Samples are named by kind and index, never by their text: positive samples look
+/// like secrets and may be real ones in a custom rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelfTestFailure {
+    /// The rule reported nothing in a sample it must report
+    Missed {
+        /// Index into the rule's positive samples
+        sample: usize,
+    },
+    /// The rule reported a match in a near miss
+    Reported {
+        /// Index into the rule's negative samples
+        sample: usize,
+    },
+    /// The rule panicked on a sample
+    Panicked {
+        /// `"positive"` or `"negative"`
+        kind: &'static str,
+        /// Index into the rule's samples of that kind
+        sample: usize,
+    },
+}
+
+impl SelfTestFailure {
+    fn to_value(&self) -> Value {
+        match self {
+            SelfTestFailure::Missed { sample } => {
+                json!({"failure": "missed", "kind": "positive", "sample": sample})
+            }
+            SelfTestFailure::Reported { sample } => {
+                json!({"failure": "reported", "kind": "negative", "sample": sample})
+            }
+            SelfTestFailure::Panicked { kind, sample } => {
+                json!({"failure": "panicked", "kind": kind, "sample": sample})
+            }
+        }
+    }
+}
+
+/// How one rule did in [`Scanner::self_test`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSelfTest {
+    /// Name of the rule's detector, e.g. `"stripe"`
+    pub rule: String,
+    /// Number of samples the rule was run on; 0 for a custom rule without a sample
+    pub samples: usize,
+    /// Time the rule took over all its samples
+    pub duration: Duration,
+    pub failures: Vec<SelfTestFailure>,
+}
+
+/// The results of [`Scanner::self_test`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfTestReport {
+    /// Every enabled rule, built-in ones first, in the order the scanner runs them
+    pub rules: Vec<RuleSelfTest>,
+    /// How long the whole test took
+    pub duration: Duration,
+}
+
+impl SelfTestReport {
+    /// Whether every rule passed on every sample
+    pub fn passed(&self) -> bool {
+        self.rules.iter().all(|rule| rule.failures.is_empty())
+    }
+
+    /// Returns the rules that failed on some sample
+    pub fn failed_rules(&self) -> Vec<&str> {
+        self.rules
+            .iter()
+            .filter(|rule| !rule.failures.is_empty())
+            .map(|rule| rule.rule.as_str())
+            .collect()
+    }
+
+    /// Serializes the report for a health endpoint
+    ///
+    /// Durations are in milliseconds. Samples are listed by kind and index only.
+    ///
+    /// # Returns
+    /// * `String` - A JSON object with `passed`, `duration_ms` and one entry per rule
+    pub fn to_json(&self) -> String {
+        let millis = |duration: &Duration| duration.as_secs_f64() * 1000.0;
+        let rules: Vec<Value> = self
+            .rules
+            .iter()
+            .map(|rule| {
+                json!({
+                    "rule": rule.rule,
+                    "samples": rule.samples,
+                    "duration_ms": millis(&rule.duration),
+                    "failures": rule
+                        .failures
+                        .iter()
+                        .map(SelfTestFailure::to_value)
+                        .collect::<Vec<Value>>(),
+                })
+            })
+            .collect();
+        json!({
+            "passed": self.passed(),
+            "duration_ms": millis(&self.duration),
+            "rules": rules,
+        })
+        .to_string()
+    }
+}
+
+/// Runs a rule over a sample, or returns `None` if it panicked
+fn detects(rule: &dyn Detector, sample: &str) -> Option<bool> {
+    catch_unwind(AssertUnwindSafe(|| !rule.detect(sample).is_empty())).ok()
+}
+
+impl Scanner {
+    /// Runs every enabled rule over its samples, to check the build at startup
+    ///
+    /// Built-in rules are run over their positive samples and near misses from
+    /// [`crate::ruleset_corpus`]; a built-in narrowed to some of its secret types is
+    /// only held to its near misses. Custom rules are run over their
+    /// [`Detector::sample`], if they have one. Each rule runs on its own, without
+    /// suppression, allowlists or other policy, and a rule that panics is reported as
+    /// failed rather than unwinding into the caller.
+    ///
+    /// # Returns
+    /// * `SelfTestReport` - Each rule's time and failures, see [`SelfTestReport::passed`]
+    pub fn self_test(&self) -> SelfTestReport {
+        let started = Instant::now();
+        let rules = self
+            .rules()
+            .map(|(rule, narrowed)| {
+                let rule_started = Instant::now();
+                let corpus = ruleset_corpus()
+                    .iter()
+                    .find(|samples| samples.rule == rule.name());
+                let (positive, negative): (Vec<&str>, Vec<&str>) = match corpus {
+                    Some(samples) => (
+                        samples.positive.iter().map(String::as_str).collect(),
+                        samples.negative.iter().map(String::as_str).collect(),
+                    ),
+                    None => (rule.sample().into_iter().collect(), Vec::new()),
+                };
+
+                let mut failures = Vec::new();
+                for (sample, text) in positive.iter().enumerate() {
+                    match detects(rule.as_ref(), text) {
+                        Some(false) if !narrowed => {
+                            failures.push(SelfTestFailure::Missed { sample })
+                        }
+                        Some(_) => {}
+                        None => failures.push(SelfTestFailure::Panicked {
+                            kind: "positive",
+                            sample,
+                        }),
+                    }
+                }
+                for (sample, text) in negative.iter().enumerate() {
+                    match detects(rule.as_ref(), text) {
+                        Some(true) => failures.push(SelfTestFailure::Reported { sample }),
+                        Some(false) => {}
+                        None => failures.push(SelfTestFailure::Panicked {
+                            kind: "negative",
+                            sample,
+                        }),
+                    }
+                }
+                RuleSelfTest {
+                    rule: rule.name().to_string(),
+                    samples: positive.len() + negative.len(),
+                    duration: rule_started.elapsed(),
+                    failures,
+                }
+            })
+            .collect();
+        SelfTestReport {
+            rules,
+            duration: started.elapsed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secrets::secret_match::SecretMatch;
+    use crate::CustomDetector;
+
+    /// A custom rule whose regex support is missing from the build
+    struct BrokenRule;
+
+    impl Detector for BrokenRule {
+        fn name(&self) -> &str {
+            "Acme Broken"
+        }
+
+        fn detect(&self, _content: &str) -> Vec<SecretMatch> {
+            panic!("regex feature unavailable")
+        }
+
+        fn sample(&self) -> Option<&str> {
+            Some("ACME_KEY=acme_live_0123456789abcdef")
+        }
+    }
+
+    #[test]
+    fn test_default_build_passes() {
+        let report = Scanner::default().self_test();
+        assert!(report.passed(), "failed: {:?}", report.failed_rules());
+        assert_eq!(report.rules.len(), ruleset_corpus().len());
+        assert!(report.rules.iter().all(|rule| rule.samples > 0));
+
+        // Narrowing a detector to some of its types doesn't fail its positive samples
+        let narrowed = Scanner::builder()
+            .disable("Basic Auth Credentials")
+            .build()
+            .unwrap();
+        assert!(narrowed.self_test().passed());
+    }
+
+    #[test]
+    fn test_broken_custom_rules_are_reported() {
+        let missed = CustomDetector::new("Acme Live Key", r"acme_live_[A-Za-z0-9]{40}")
+            .unwrap()
+            .sample("ACME_KEY=acme_live_0123456789abcdef");
+        let scanner = Scanner::builder()
+            .custom(missed)
+            .custom(BrokenRule)
+            .build()
+            .unwrap();
+        let report = scanner.self_test();
+
+        assert!(!report.passed());
+        assert_eq!(report.failed_rules(), vec!["Acme Live Key", "Acme Broken"]);
+        let failures = |name: &str| {
+            report
+                .rules
+                .iter()
+                .find(|rule| rule.rule == name)
+                .unwrap()
+                .failures
+                .clone()
+        };
+        assert_eq!(
+            failures("Acme Live Key"),
+            vec![SelfTestFailure::Missed { sample: 0 }]
+        );
+        assert_eq!(
+            failures("Acme Broken"),
+            vec![SelfTestFailure::Panicked {
+                kind: "positive",
+                sample: 0
+            }]
+        );
+
+        let json: Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["passed"], false);
+        assert!(!report.to_json().contains("acme_live_0123456789abcdef"));
+        let broken = json["rules"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|rule| rule["rule"] == "Acme Broken")
+            .unwrap();
+        assert_eq!(
+            broken["failures"],
+            json!([{"failure": "panicked", "kind": "positive", "sample": 0}])
+        );
+    }
+}